@@ -1,9 +1,18 @@
 #![allow(dead_code)]
 #![feature(box_into_raw_non_null)]
 #![feature(box_syntax)]
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(test)]
+#[macro_use]
+extern crate static_assertions;
+use std::cmp::Ordering;
+use std::collections::VecDeque;
 use std::fmt;
-use std::iter::FromIterator;
+use std::hash::{Hash, Hasher};
+use std::iter::{FromIterator, FusedIterator};
 use std::marker::PhantomData;
+use std::ops::{Index, IndexMut, Neg};
 use std::ptr::NonNull;
 
 /// A doubly-linked list with owned nodes.
@@ -47,6 +56,109 @@ impl<T> LinkedList<T> {
             marker: PhantomData,
         }
     }
+
+    /// Returns the number of elements in the list
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the list contains no elements
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Get the first element of the list
+    pub fn front(&self) -> Option<&T> {
+        self.head.map(|node| unsafe { &(*node.as_ptr()).element })
+    }
+    /// Get the last element of the list
+    pub fn back(&self) -> Option<&T> {
+        self.tail.map(|node| unsafe { &(*node.as_ptr()).element })
+    }
+    /// Get a mutable reference to the first element of the list
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.head
+            .map(|node| unsafe { &mut (*node.as_ptr()).element })
+    }
+    /// Get a mutable reference to the last element of the list
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.tail
+            .map(|node| unsafe { &mut (*node.as_ptr()).element })
+    }
+
+    /// Remove and drop every element of the list
+    pub fn clear(&mut self) {
+        *self = LinkedList::new();
+    }
+
+    /// Add `item` to the front of the list
+    pub fn push_front(&mut self, item: T) {
+        let mut node = box Node::new(item);
+        node.next = self.head;
+
+        unsafe {
+            let node_ptr = Some(Box::into_raw_non_null(node));
+            match self.head {
+                None => self.tail = node_ptr,
+                Some(mut head) => head.as_mut().prev = node_ptr,
+            }
+            self.head = node_ptr;
+        }
+        self.len += 1;
+    }
+    /// Add `item` to the back of the list
+    pub fn push_back(&mut self, item: T) {
+        let mut node = box Node::new(item);
+        node.prev = self.tail;
+
+        unsafe {
+            let node_ptr = Some(Box::into_raw_non_null(node));
+            match self.tail {
+                None => self.head = node_ptr,
+                Some(mut tail) => tail.as_mut().next = node_ptr,
+            }
+            self.tail = node_ptr;
+        }
+        self.len += 1;
+    }
+
+    /// Remove and return the first element of the list
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.head.map(|node| unsafe {
+            self.len -= 1;
+
+            let node = Box::from_raw(node.as_ptr());
+            self.head = node.next;
+            match node.next {
+                None => self.tail = None,
+                Some(mut next) => next.as_mut().prev = None,
+            }
+            Node::into_element(node)
+        })
+    }
+    /// Remove and return the last element of the list
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.tail.map(|node| unsafe {
+            self.len -= 1;
+
+            let node = Box::from_raw(node.as_ptr());
+            self.tail = node.prev;
+            match node.prev {
+                None => self.head = None,
+                Some(mut prev) => prev.as_mut().next = None,
+            }
+            Node::into_element(node)
+        })
+    }
+
+    /// Move all of the elements of `other` onto the end of the list, leaving
+    /// `other` empty
+    ///
+    /// This relinks the nodes of `other` in constant time.
+    pub fn append(&mut self, other: &mut LinkedList<T>) {
+        self.cursor_back_mut().absorb_cursor(other.cursor_mut());
+    }
+
     /// Provides a cursor to the empty element
     pub fn cursor(&self) -> Cursor<T> {
         Cursor {
@@ -63,572 +175,5634 @@ impl<T> LinkedList<T> {
             current_len: 0,
         }
     }
-    /* other list methods go here */
-}
 
-impl<T> Drop for LinkedList<T> {
-    fn drop(&mut self) {
-        let mut c = self.cursor_mut();
-        while c.pop().is_some() {}
+    /// Provides a cursor at the first element, or the empty element if the
+    /// list is empty
+    pub fn cursor_front(&self) -> Cursor<'_, T> {
+        Cursor {
+            list: self,
+            current: self.head,
+        }
+    }
+    /// Provides a cursor at the last element, or the empty element if the list
+    /// is empty
+    pub fn cursor_back(&self) -> Cursor<'_, T> {
+        Cursor {
+            list: self,
+            current: self.tail,
+        }
     }
-}
 
-impl<T: fmt::Debug> fmt::Debug for LinkedList<T> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let mut t = f.debug_list();
+    /// Provides a mutable cursor at the first element, or the empty element if
+    /// the list is empty
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut {
+            current: self.head,
+            current_len: if self.len == 0 { 0 } else { 1 },
+            list: self,
+        }
+    }
+    /// Provides a mutable cursor at the last element, or the empty element if
+    /// the list is empty
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut {
+            current: self.tail,
+            current_len: self.len,
+            list: self,
+        }
+    }
+
+    /// Provides a forward iterator
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            head: self.head,
+            tail: self.tail,
+            len: self.len,
+            marker: PhantomData,
+        }
+    }
+
+    /// Provides a forward iterator with mutable references
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            head: self.head,
+            tail: self.tail,
+            len: self.len,
+            marker: PhantomData,
+        }
+    }
+
+    /// Remove all of the elements from the list, yielding them front to back
+    ///
+    /// Any elements that aren't iterated over are dropped along with the
+    /// iterator, leaving the list empty.
+    pub fn drain(&mut self) -> Drain<'_, T> {
+        Drain { list: self }
+    }
+
+    /// Lazily remove and yield the elements satisfying `f`, which may also
+    /// modify the elements it is passed
+    ///
+    /// Elements that haven't been reached when the iterator is dropped are
+    /// left in the list.
+    pub fn extract_if<F>(&mut self, f: F) -> ExtractIf<'_, T, F>
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        ExtractIf {
+            next: self.head,
+            list: self,
+            pred: f,
+        }
+    }
+
+    /// Create a list holding only `sentinel`, along with a handle to its node
+    ///
+    /// The sentinel is an ordinary element, so it stays at the front only as
+    /// long as nothing is inserted before it or removes it.
+    pub fn with_sentinel(sentinel: T) -> (LinkedList<T>, NodeHandle<T>) {
+        let mut list = LinkedList::new();
+        list.push_front(sentinel);
+        let handle = NodeHandle {
+            node: list.head.unwrap(),
+        };
+        (list, handle)
+    }
+
+    /// Build a list by handing a cursor into a new, empty list to `f`
+    pub fn build<F>(f: F) -> LinkedList<T>
+    where
+        F: FnOnce(&mut CursorMut<T>),
+    {
+        let mut list = LinkedList::new();
+        f(&mut list.cursor_mut());
+        list
+    }
+
+    /// Find the first pair of adjacent elements satisfying `pred`
+    ///
+    /// Returns the index of the first element of the pair along with
+    /// references to both elements.
+    pub fn find_pair<F>(&self, mut pred: F) -> Option<(usize, &T, &T)>
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
         let mut c = self.cursor();
         c.move_next();
-        while let Some(e) = c.current() {
-            t.entry(&e);
+        let mut index = 0;
+        while let (Some(a), Some(b)) = (c.current(), c.peek()) {
+            if pred(a, b) {
+                return Some((index, a, b));
+            }
             c.move_next();
+            index += 1;
         }
-
-        t.finish()
+        None
     }
-}
-
-impl<T> FromIterator<T> for LinkedList<T> {
-    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> LinkedList<T> {
+    /// Build a list from `n` back-to-back passes over `iter`
+    pub fn from_repeating_iter<I>(iter: I, n: usize) -> LinkedList<T>
+    where
+        I: Clone + IntoIterator<Item = T>,
+    {
         let mut list = LinkedList::new();
         {
             let mut cursor = list.cursor_mut();
-            for el in iter {
-                cursor.insert_before(el);
+            for _ in 0..n {
+                for el in iter.clone() {
+                    cursor.insert_before(el);
+                }
             }
         }
         list
     }
-}
-
-/// An Immutable look into a `LinkedList` that can be moved back and forth
-pub struct Cursor<'list, T: 'list> {
-    current: Option<NonNull<Node<T>>>,
-    list: &'list LinkedList<T>,
-}
+    /// Merge each pair of consecutive elements with `f`, so that
+    /// `[a, b, c, d]` becomes `[f(a, b), f(c, d)]`
+    ///
+    /// If the list has odd length the last element is kept as is.
+    pub fn merge_adjacent_pairs<F>(mut self, mut f: F) -> LinkedList<T>
+    where
+        F: FnMut(T, T) -> T,
+    {
+        let mut merged = LinkedList::new();
+        {
+            let mut src = self.cursor_mut();
+            let mut dst = merged.cursor_mut();
+            while let Some(a) = src.pop() {
+                match src.pop() {
+                    Some(b) => dst.insert_before(f(a, b)),
+                    None => dst.insert_before(a),
+                }
+            }
+        }
+        merged
+    }
+    /// Pair each element with its index, consuming the list
+    pub fn into_indexed_list(mut self) -> LinkedList<(usize, T)> {
+        let mut indexed = LinkedList::new();
+        {
+            let mut src = self.cursor_mut();
+            let mut dst = indexed.cursor_mut();
+            let mut index = 0;
+            while let Some(el) = src.pop() {
+                dst.insert_before((index, el));
+                index += 1;
+            }
+        }
+        indexed
+    }
+    /// Remove the longest prefix of elements satisfying `f`, returning it as a
+    /// new list
+    ///
+    /// The nodes are relinked into the returned list rather than copied.
+    pub fn drain_while<F>(&mut self, mut f: F) -> LinkedList<T>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        use std::mem::replace;
 
-impl<'list, T> Cursor<'list, T> {
-    fn next(&self) -> Option<NonNull<Node<T>>> {
-        self.current
-            .map_or(self.list.head, |node| unsafe { node.as_ref().next })
+        let rest = {
+            let mut c = self.cursor_mut();
+            while let Some(next) = c.peek() {
+                if !f(next) {
+                    break;
+                }
+                c.move_next();
+            }
+            c.split()
+        };
+        replace(self, rest)
     }
-    fn prev(&self) -> Option<NonNull<Node<T>>> {
-        self.current
-            .map_or(self.list.tail, |node| unsafe { node.as_ref().prev })
+    /// Lazily split the list into consecutive chunks of `n` elements, the last
+    /// of which may be shorter
+    ///
+    /// # Panics
+    /// Panics if `n` is zero
+    pub fn into_iter_chunks(self, n: usize) -> IntoIterChunks<T> {
+        assert!(n != 0, "chunk size must be non-zero");
+        IntoIterChunks {
+            list: self,
+            chunk_size: n,
+        }
     }
-    /// Move to the subsequent element of the list if it exists or the empty
-    /// element
-    pub fn move_next(&mut self) {
-        self.current = self.next()
+    /// Lazily apply `f` to each element, yielding only the `Some` results
+    ///
+    /// Each node is freed as it is consumed.
+    pub fn into_iter_filter_map<B, F>(self, f: F) -> IntoIterFilterMap<T, F>
+    where
+        F: FnMut(T) -> Option<B>,
+    {
+        IntoIterFilterMap { list: self, f }
     }
-    /// Move to the previous element of the list
-    pub fn move_prev(&mut self) {
-        self.current = self.prev();
+    /// Find the smallest and largest elements in a single pass, consuming the
+    /// list
+    ///
+    /// As with `Iterator::min` and `Iterator::max`, ties go to the first
+    /// minimum and the last maximum. The element is cloned if the list has
+    /// only one.
+    pub fn into_min_max_pair(mut self) -> Option<(T, T)>
+    where
+        T: Ord + Clone,
+    {
+        let mut c = self.cursor_mut();
+        let first = c.pop()?;
+        let (mut min, mut max) = (first.clone(), first);
+        while let Some(el) = c.pop() {
+            if el < min {
+                min = el;
+            } else if el >= max {
+                max = el;
+            }
+        }
+        Some((min, max))
     }
-
-    /// Get the current element
-    pub fn current(&self) -> Option<&'list T> {
-        self.current.map(|node| unsafe {
-            // Need an unbound lifetime to get 'list
-            let node = &*node.as_ptr();
-            &node.element
-        })
+    /// Split the list in two at its midpoint, with the first half getting the
+    /// extra element when the length is odd
+    pub fn into_two_halves(mut self) -> (LinkedList<T>, LinkedList<T>) {
+        let mut slow = match self.head {
+            Some(head) => head,
+            None => return (self, LinkedList::new()),
+        };
+        // `fast` moves two elements for every one that `slow` does, so `slow`
+        // is at the midpoint by the time `fast` reaches the tail
+        let mut fast = slow;
+        let mut first_len = 1;
+        unsafe {
+            while let Some(next) = fast.as_ref().next {
+                match next.as_ref().next {
+                    Some(next) => fast = next,
+                    None => break,
+                }
+                slow = slow.as_ref().next.unwrap();
+                first_len += 1;
+            }
+        }
+        let second = CursorMut {
+            list: &mut self,
+            current: Some(slow),
+            current_len: first_len,
+        }
+        .split();
+        (self, second)
     }
-    /// Get the following element
-    pub fn peek(&self) -> Option<&'list T> {
-        self.next().map(|next_node| unsafe {
-            let next_node = &*next_node.as_ptr();
-            &next_node.element
-        })
+    /// Group the list into arrays of `N` consecutive elements, consuming it
+    ///
+    /// Any elements left over at the end that can't fill an array are dropped.
+    ///
+    /// # Panics
+    /// Panics if `N` is zero
+    pub fn nwise<const N: usize>(self) -> NWise<T, N> {
+        assert!(N != 0, "group size must be non-zero");
+        NWise { list: self }
     }
-    /// Get the previous element
-    pub fn peek_before(&self) -> Option<&'list T> {
-        self.prev().map(|prev_node| unsafe {
-            let prev_node = &*prev_node.as_ptr();
-            &prev_node.element
-        })
+    /// Keep only the elements satisfying `f`, dropping the rest in place
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut c = self.cursor_mut();
+        while let Some(next) = c.peek() {
+            if f(next) {
+                c.move_next();
+            } else {
+                c.pop();
+            }
+        }
     }
-}
-
-/// A mutable view into a `LinkedList` that can be used to edit the collection
-pub struct CursorMut<'list, T: 'list> {
-    current: Option<NonNull<Node<T>>>,
-    list: &'list mut LinkedList<T>,
-    current_len: usize,
-}
-
-impl<'list, T> CursorMut<'list, T> {
-    fn next(&self) -> Option<NonNull<Node<T>>> {
-        self.current
-            .map_or(self.list.head, |node| unsafe { node.as_ref().next })
+    /// Keep only the elements satisfying `f`, which may also modify the
+    /// elements it is passed
+    pub fn retain_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        let mut c = self.cursor_mut();
+        c.move_next();
+        while let Some(current) = c.current() {
+            if f(current) {
+                c.move_next();
+            } else {
+                c.remove_current();
+            }
+        }
     }
-    fn prev(&self) -> Option<NonNull<Node<T>>> {
-        self.current
-            .map_or(self.list.tail, |node| unsafe { node.as_ref().prev })
+    /// Remove consecutive repeated elements
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b)
     }
-    // `current_len` is in the range 0...self.list.len at all times
-    fn inc_len(&mut self) {
-        self.current_len += 1;
-        self.current_len %= self.list.len + 1;
+    /// Remove consecutive elements that map to the same key
+    pub fn dedup_by_key<K, F>(&mut self, mut key: F)
+    where
+        K: PartialEq,
+        F: FnMut(&mut T) -> K,
+    {
+        self.dedup_by(|a, b| key(a) == key(b))
     }
-    fn dec_len(&mut self) {
-        self.current_len += self.list.len;
-        self.current_len %= self.list.len + 1;
+    /// Remove consecutive elements for which `same_bucket` returns true
+    ///
+    /// As with `Vec::dedup_by`, `same_bucket` is passed each element followed
+    /// by the last element that was kept before it, and the first of these is
+    /// removed if it returns true.
+    pub fn dedup_by<F>(&mut self, mut same_bucket: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        let mut c = self.cursor_mut();
+        c.move_next();
+        while let (Some(current), Some(next)) = (c.current, c.next_node()) {
+            let same = unsafe {
+                same_bucket(
+                    &mut (*next.as_ptr()).element,
+                    &mut (*current.as_ptr()).element,
+                )
+            };
+            if same {
+                c.pop();
+            } else {
+                c.move_next();
+            }
+        }
     }
+    /// Reverse the order of the list in place
+    pub fn reverse(&mut self) {
+        use std::mem::swap;
 
-    /// Move to the subsequent element of the list if it exists or the empty
-    /// element
-    pub fn move_next(&mut self) {
-        self.inc_len();
-        self.current = self.next()
+        let mut node = self.head;
+        while let Some(mut n) = node {
+            unsafe {
+                let n = n.as_mut();
+                swap(&mut n.next, &mut n.prev);
+                // what was `next` is now `prev`
+                node = n.prev;
+            }
+        }
+        swap(&mut self.head, &mut self.tail);
     }
-    /// Move to the previous element of the list
-    pub fn move_prev(&mut self) {
-        self.dec_len();
-        self.current = self.prev()
+    /// Split the list into the elements that satisfy `f` and those that
+    /// don't, keeping their relative order
+    ///
+    /// The existing nodes are relinked into the two new lists.
+    pub fn partition<F>(mut self, mut f: F) -> (LinkedList<T>, LinkedList<T>)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut matching = LinkedList::new();
+        let mut rest = LinkedList::new();
+        while let Some(node) = self.pop_front_node() {
+            if f(unsafe { &node.as_ref().element }) {
+                matching.push_back_node(node);
+            } else {
+                rest.push_back_node(node);
+            }
+        }
+        (matching, rest)
     }
 
-    /// Get the current element
-    pub fn current(&mut self) -> Option<&mut T> {
-        self.current.map(|node| unsafe {
-            // Need an unbound lifetime to get same lifetime as self
-            let node = &mut *node.as_ptr();
-            &mut node.element
-        })
+    /// Sort the list in place
+    ///
+    /// This is a stable bottom-up merge sort, relinking the existing nodes
+    /// rather than moving elements between them.
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_by(|a, b| a.cmp(b))
     }
-    /// Get the next element
-    pub fn peek(&mut self) -> Option<&mut T> {
-        self.next().map(|next_node| unsafe {
-            let next_node = &mut *next_node.as_ptr();
-            &mut next_node.element
-        })
-    }
-    /// Get the previous element
-    pub fn peek_before(&self) -> Option<&mut T> {
-        self.prev().map(|prev_node| unsafe {
-            let prev_node = &mut *prev_node.as_ptr();
-            &mut prev_node.element
-        })
+    /// Sort the list in place by the key `f` extracts from each element
+    pub fn sort_by_key<K, F>(&mut self, mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.sort_by(|a, b| f(a).cmp(&f(b)))
     }
+    /// Sort the list in place with the comparison function `cmp`
+    pub fn sort_by<F>(&mut self, mut cmp: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        use std::mem::replace;
 
-    /// Get an immutable cursor at the current element
-    pub fn as_cursor(&self) -> Cursor<T> {
-        Cursor {
-            current: self.current,
-            list: self.list,
-        }
+        let list = replace(self, LinkedList::new());
+        *self = list.merge_sort(&mut cmp);
     }
 
-    // Now the list editing operations
-
-    /// Insert `item` after the cursor
-    pub fn insert(&mut self, item: T) {
-        let mut node = box Node::new(item);
-        node.prev = self.current;
-        node.next = self.next();
+    /// Merge the sorted list `other` into this sorted list, keeping it sorted
+    ///
+    /// Both lists must already be sorted, which is only checked in debug
+    /// builds. Nodes are relinked rather than reallocated, and elements from
+    /// `self` come before equal elements from `other`.
+    pub fn merge(&mut self, other: LinkedList<T>)
+    where
+        T: Ord,
+    {
+        self.merge_by(other, |a, b| a.cmp(b))
+    }
+    /// Merge the sorted list `other` into this sorted list using the
+    /// comparison function `cmp`
+    pub fn merge_by<F>(&mut self, other: LinkedList<T>, mut cmp: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        use std::mem::replace;
 
-        unsafe {
-            let node_ptr = Some(Box::into_raw_non_null(node));
-            match self.next() {
-                None => self.list.tail = node_ptr,
-                Some(mut next) => next.as_mut().prev = node_ptr,
-            }
-            match self.current {
-                None => self.list.head = node_ptr,
-                Some(mut prev) => prev.as_mut().next = node_ptr,
-            }
-        }
-        self.list.len += 1;
+        debug_assert!(
+            self.find_pair(|a, b| cmp(a, b) == Ordering::Greater)
+                .is_none(),
+            "merge called on an unsorted list"
+        );
+        debug_assert!(
+            other
+                .find_pair(|a, b| cmp(a, b) == Ordering::Greater)
+                .is_none(),
+            "merge called with an unsorted list"
+        );
+        let list = replace(self, LinkedList::new());
+        *self = list.merge_sorted(other, &mut cmp);
     }
-    /// Insert `item` before the cursor
-    pub fn insert_before(&mut self, item: T) {
-        let mut node = box Node::new(item);
-        node.prev = self.prev();
-        node.next = self.current;
 
-        unsafe {
-            let node_ptr = Some(Box::into_raw_non_null(node));
-            match self.prev() {
-                None => self.list.head = node_ptr,
-                Some(mut next) => next.as_mut().next = node_ptr,
-            }
-            match self.current {
-                None => self.list.tail = node_ptr,
-                Some(mut prev) => prev.as_mut().prev = node_ptr,
+    // Bottom-up merge sort: each pass splits sorted runs of `width` elements
+    // off the front of the list and merges them in pairs, doubling `width`
+    // until a single run is left.
+    fn merge_sort<F>(mut self, cmp: &mut F) -> LinkedList<T>
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut width = 1;
+        while width < self.len {
+            let mut merged = LinkedList::new();
+            {
+                let mut src = self.cursor_mut();
+                while src.peek().is_some() {
+                    let first = src.splice_after(width);
+                    let second = src.splice_after(width);
+                    merged.append(&mut first.merge_sorted(second, cmp));
+                }
             }
+            self = merged;
+            width *= 2;
         }
-        self.list.len += 1;
-        self.inc_len();
+        self
     }
 
-    /// Insert `list` between the current element and the next
-    pub fn insert_list(&mut self, list: LinkedList<T>) {
-        match (list.head, list.tail) {
-            (Some(mut head), Some(mut tail)) => unsafe {
-                head.as_mut().prev = self.current;
-                tail.as_mut().next = self.next();
-            },
-            //splicing in an empty list should be a no-op
-            (None, None) => return,
-            _ => unreachable!(),
+    // Merge two sorted lists by relinking their nodes. On ties elements from
+    // `self` come first, which keeps the merge stable.
+    fn merge_sorted<F>(mut self, mut other: LinkedList<T>, cmp: &mut F) -> LinkedList<T>
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut merged = LinkedList::new();
+        while let (Some(a), Some(b)) = (self.head, other.head) {
+            let take_other =
+                unsafe { cmp(&b.as_ref().element, &a.as_ref().element) == Ordering::Less };
+            let node = if take_other {
+                other.pop_front_node()
+            } else {
+                self.pop_front_node()
+            };
+            merged.push_back_node(node.unwrap());
         }
-        unsafe {
-            match self.next() {
-                None => self.list.tail = list.tail,
-                Some(mut next) => next.as_mut().prev = list.tail,
-            }
-            match self.current {
-                None => self.list.head = list.head,
-                Some(mut prev) => prev.as_mut().next = list.head,
-            }
+        // at most one of the lists still has elements, which all belong at the
+        // end
+        {
+            let mut c = merged.cursor_back_mut();
+            c.absorb_cursor(self.cursor_mut());
+            c.absorb_cursor(other.cursor_mut());
         }
-        self.list.len += list.len;
+        merged
     }
 
-    /// Insert `list` between the previous element and current
-    pub fn insert_list_before(&mut self, list: LinkedList<T>) {
-        match (list.head, list.tail) {
-            (Some(mut head), Some(mut tail)) => unsafe {
-                head.as_mut().prev = self.prev();
-                tail.as_mut().next = self.current;
-            },
-            //splicing in an empty list should be a no-op
-            (None, None) => return,
-            _ => unreachable!(),
-        }
-        unsafe {
-            match self.prev() {
-                None => self.list.head = list.head,
-                Some(mut next) => next.as_mut().next = list.head,
-            }
-            match self.current {
-                None => self.list.tail = list.tail,
-                Some(mut prev) => prev.as_mut().prev = list.tail,
-            }
+    /// Unlink `node` from wherever it is in the list, leaving the caller
+    /// responsible for it
+    ///
+    /// `node` must belong to this list.
+    unsafe fn unlink_node(&mut self, mut node: NonNull<Node<T>>) {
+        let node = node.as_mut();
+        match node.prev {
+            None => self.head = node.next,
+            Some(mut prev) => prev.as_mut().next = node.next,
         }
-        self.list.len += list.len;
-        if self.current_len != 0 {
-            self.current_len += list.len;
+        match node.next {
+            None => self.tail = node.prev,
+            Some(mut next) => next.as_mut().prev = node.prev,
         }
+        node.prev = None;
+        node.next = None;
+        self.len -= 1;
     }
-
-    /// Remove and return the item following the cursor
-    pub fn pop(&mut self) -> Option<T> {
-        self.next().map(|node| unsafe {
-            self.list.len -= 1;
-            self.current_len %= self.list.len + 1;
-
-            let node = Box::from_raw(node.as_ptr());
-            match self.current {
-                None => self.list.head = node.next,
-                Some(mut prev) => prev.as_mut().next = node.next,
-            }
-            match node.next {
-                None => self.list.tail = self.current,
-                Some(mut next) => {
-                    next.as_mut().prev = self.current;
-                }
+    /// Unlink the first node, leaving the caller responsible for it
+    fn pop_front_node(&mut self) -> Option<NonNull<Node<T>>> {
+        self.head.map(|mut node| unsafe {
+            self.len -= 1;
+            self.head = node.as_ref().next;
+            match self.head {
+                None => self.tail = None,
+                Some(mut head) => head.as_mut().prev = None,
             }
-            Node::into_element(node)
+            node.as_mut().next = None;
+            node
         })
     }
-    /// Remove and return the item before the cursor
-    pub fn pop_prev(&mut self) -> Option<T> {
-        self.prev().map(|node| unsafe {
-            self.list.len -= 1;
-            self.dec_len();
-
-            let node = Box::from_raw(node.as_ptr());
-            match node.prev {
-                None => self.list.head = self.current,
-                Some(mut prev) => prev.as_mut().next = self.current,
-            }
-            match self.current {
-                None => self.list.tail = node.prev,
-                Some(mut next) => next.as_mut().prev = node.prev,
+    /// Link an unlinked node onto the end of the list
+    fn push_back_node(&mut self, mut node: NonNull<Node<T>>) {
+        unsafe {
+            node.as_mut().prev = self.tail;
+            node.as_mut().next = None;
+            match self.tail {
+                None => self.head = Some(node),
+                Some(mut tail) => tail.as_mut().next = Some(node),
             }
-            Node::into_element(node)
-        })
+        }
+        self.tail = Some(node);
+        self.len += 1;
     }
-
-    fn split_at(self, current: NonNull<Node<T>>, split_len: usize) -> LinkedList<T> {
-        let total_len = self.list.len;
-
-        let next = unsafe { (*current.as_ptr()).next };
-
-        if let Some(next) = next {
-            let new_head = Some(next);
-            let new_tail = self.list.tail.take();
-            let new_len = total_len - split_len;
-
-            let old_head = self.list.head;
-            let old_tail = Some(current);
-            let old_len = total_len - new_len;
-
-            unsafe {
-                (*current.as_ptr()).next = None;
-                (*next.as_ptr()).prev = None;
-            }
-
-            self.list.head = old_head;
-            self.list.tail = old_tail;
-            self.list.len = old_len;
-
-            LinkedList {
-                head: new_head,
-                tail: new_tail,
-                len: new_len,
-                marker: PhantomData,
-            }
-        } else {
-            LinkedList::new()
+    /// Split the list in two at `index`, returning the elements from `index`
+    /// onwards
+    ///
+    /// # Panics
+    /// Panics if `index > len`
+    pub fn split_at_index(&mut self, index: usize) -> LinkedList<T> {
+        assert!(
+            index <= self.len,
+            "index {} out of bounds for list of length {}",
+            index,
+            self.len
+        );
+        let mut c = self.cursor_mut();
+        if index > 0 {
+            c.seek_to(index - 1);
         }
+        c.split()
     }
-
-    /// Split the list in two after the current element
-    /// The returned list consists of all elements following the current one.
-    // note: consuming the cursor is not necessary here, but it makes sense
-    // given the interface
-    pub fn split(self) -> LinkedList<T> {
-        use std::mem::replace;
-
-        match self.current {
-            None => replace(self.list, LinkedList::new()),
-
-            Some(current) => {
-                let split_len = self.current_len;
-                self.split_at(current, split_len)
-            }
+    /// Shorten the list to its first `len` elements, dropping the rest
+    ///
+    /// Nothing happens if the list is already no longer than `len`.
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len {
+            self.split_at_index(len);
         }
     }
-
-    /// Split the list in two before the current element
-    pub fn split_before(self) -> LinkedList<T> {
-        use std::mem::replace;
-
-        match self.current {
-            None => replace(self.list, LinkedList::new()),
-            Some(current) => match unsafe { (*current.as_ptr()).prev } {
-                None => replace(self.list, LinkedList::new()),
-                Some(prev) => {
-                    let split_len = self.current_len - 1;
-                    self.split_at(prev, split_len)
-                }
-            },
+    /// Returns `true` if the list contains an element equal to `item`
+    pub fn contains(&self, item: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.contains_by(|el| el == item)
+    }
+    /// Returns `true` if the list contains an element satisfying `f`
+    pub fn contains_by<F>(&self, f: F) -> bool
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.iter().any(f)
+    }
+    /// Provides a cursor at the first element satisfying `f`, if there is one
+    pub fn find_cursor<F>(&self, mut f: F) -> Option<Cursor<'_, T>>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut c = self.cursor();
+        c.move_next();
+        while let Some(current) = c.current() {
+            if f(current) {
+                return Some(c);
+            }
+            c.move_next();
         }
+        None
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use std::fmt::Debug;
-    use std::iter::FromIterator;
-
-    use super::{Cursor, CursorMut, LinkedList};
-
-    fn mut_cmp_iterator<T, I>(list: &mut LinkedList<T>, iter: I)
+    /// Provides a mutable cursor at the first element satisfying `f`, if
+    /// there is one
+    pub fn find_cursor_mut<F>(&mut self, mut f: F) -> Option<CursorMut<'_, T>>
     where
-        T: PartialEq + Debug,
-        I: IntoIterator<Item = T> + Clone + Iterator + DoubleEndedIterator<Item = T>,
+        F: FnMut(&T) -> bool,
     {
-        {
-            let mut cursor = list.cursor_mut();
-            for i in iter.clone() {
-                cursor.move_next();
-                let mut i = i;
-                assert_eq!(cursor.current(), Some(&mut i));
+        let mut c = self.cursor_mut();
+        c.move_next();
+        while let Some(current) = c.current() {
+            if f(current) {
+                return Some(c);
             }
-            cursor.move_next();
-            assert_eq!(cursor.current(), None);
+            c.move_next();
         }
-        {
-            let mut cursor = list.cursor_mut();
-            let iter = iter.rev();
-
-            for i in iter {
-                cursor.move_prev();
-                let mut i = i;
-                assert_eq!(cursor.current(), Some(&mut i));
+        None
+    }
+    /// Returns the index of the first element satisfying `f`
+    pub fn position<F>(&self, mut f: F) -> Option<usize>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut c = self.cursor();
+        c.move_next();
+        let mut index = 0;
+        while let Some(current) = c.current() {
+            if f(current) {
+                return Some(index);
             }
-            cursor.move_prev();
-            assert_eq!(cursor.current(), None);
+            c.move_next();
+            index += 1;
         }
+        None
     }
-
-    fn cmp_iterator<T, I>(list: &LinkedList<T>, iter: I)
+    /// Returns the index of the last element satisfying `f`, searching from
+    /// the back of the list
+    pub fn rposition<F>(&self, mut f: F) -> Option<usize>
     where
-        T: PartialEq + Debug,
-        I: IntoIterator<Item = T> + Clone + Iterator + DoubleEndedIterator<Item = T>,
+        F: FnMut(&T) -> bool,
     {
-        {
-            // test raw links
-            let mut cursor = list.cursor();
-            cursor.move_next();
-            while let Some(current) = cursor.current {
-                if let Some(next) = unsafe { (*current.as_ptr()).next } {
-                    assert_eq!(unsafe { (*next.as_ptr()).prev }, Some(current));
-                }
-                cursor.move_next();
+        let mut c = self.cursor();
+        c.move_prev();
+        let mut index = self.len;
+        while let Some(current) = c.current() {
+            index -= 1;
+            if f(current) {
+                return Some(index);
             }
+            c.move_prev();
         }
-        {
-            // test forwards iteration
+        None
+    }
+    /// Get the element at `index`, or `None` if it is out of bounds
+    ///
+    /// This walks the list from the head, so takes O(n) time.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.iter().nth(index)
+    }
+    /// Get a mutable reference to the element at `index`, or `None` if it is
+    /// out of bounds
+    ///
+    /// As with `get`, this takes O(n) time.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.iter_mut().nth(index)
+    }
+    /// Remove the first element equal to `item`, returning whether one was
+    /// found
+    pub fn remove(&mut self, item: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        let mut c = self.cursor_mut();
+        while let Some(next) = c.peek() {
+            if *next == *item {
+                c.pop();
+                return true;
+            }
+            c.move_next();
+        }
+        false
+    }
+    /// Remove every element equal to `item`, returning how many were removed
+    pub fn remove_all(&mut self, item: &T) -> usize
+    where
+        T: PartialEq,
+    {
+        let mut removed = 0;
+        let mut c = self.cursor_mut();
+        while let Some(next) = c.peek() {
+            if *next == *item {
+                c.pop();
+                removed += 1;
+            } else {
+                c.move_next();
+            }
+        }
+        removed
+    }
+    /// Remove and return the element at `index`, or `None` if it is out of
+    /// bounds
+    pub fn remove_at(&mut self, index: usize) -> Option<T> {
+        if index >= self.len {
+            return None;
+        }
+        let mut c = self.cursor_mut();
+        c.seek_to(index);
+        c.remove_current()
+    }
+    /// Insert `item` at `index`, shifting the element there and everything
+    /// after it back by one
+    ///
+    /// # Panics
+    /// Panics if `index > len`
+    pub fn insert_at(&mut self, index: usize, item: T) {
+        let len = self.len;
+        assert!(
+            index <= len,
+            "index {} out of bounds for list of length {}",
+            index,
+            len
+        );
+        // at `len` the cursor stays at the empty element, which inserts at
+        // the back
+        let mut c = self.cursor_mut();
+        if index < len {
+            c.seek_to(index);
+        }
+        c.insert_before(item);
+    }
+    /// Provides an iterator over every run of `N` consecutive elements, with
+    /// each window starting one element after the last
+    ///
+    /// Nothing is yielded if the list is shorter than `N`.
+    ///
+    /// # Panics
+    /// Panics if `N` is zero
+    pub fn windows<const N: usize>(&self) -> Windows<'_, T, N> {
+        assert!(N != 0, "window size must be non-zero");
+        Windows {
+            head: self.head,
+            remaining: (self.len + 1).saturating_sub(N),
+            marker: PhantomData,
+        }
+    }
+    /// Provides an iterator over consecutive chunks of `n` elements, the last
+    /// of which may be shorter
+    ///
+    /// # Panics
+    /// Panics if `n` is zero
+    pub fn chunks(&self, n: usize) -> Chunks<'_, T> {
+        assert!(n != 0, "chunk size must be non-zero");
+        Chunks {
+            iter: self.iter(),
+            chunk_size: n,
+        }
+    }
+    /// Rotate the list `k` places to the left, so that the element at index
+    /// `k` becomes the first
+    ///
+    /// The list is split and relinked rather than having its elements moved.
+    ///
+    /// # Panics
+    /// Panics if `k > len`
+    pub fn rotate_left(&mut self, k: usize) {
+        let mut rotated = self.split_at_index(k);
+        rotated.append(self);
+        *self = rotated;
+    }
+    /// Rotate the list `k` places to the right, so that the last `k` elements
+    /// come first
+    ///
+    /// # Panics
+    /// Panics if `k > len`
+    pub fn rotate_right(&mut self, k: usize) {
+        assert!(
+            k <= self.len,
+            "index {} out of bounds for list of length {}",
+            k,
+            self.len
+        );
+        let len = self.len;
+        self.rotate_left(len - k);
+    }
+    /// Combine two lists by alternating between their elements, starting
+    /// with `self`
+    ///
+    /// Once the shorter list runs out the rest of the longer one is appended.
+    /// Nodes are relinked rather than reallocated.
+    pub fn interleave(mut self, mut other: LinkedList<T>) -> LinkedList<T> {
+        let mut interleaved = LinkedList::new();
+        while let Some(node) = self.pop_front_node() {
+            interleaved.push_back_node(node);
+            match other.pop_front_node() {
+                Some(node) => interleaved.push_back_node(node),
+                None => break,
+            }
+        }
+        {
+            let mut c = interleaved.cursor_back_mut();
+            c.absorb_cursor(self.cursor_mut());
+            c.absorb_cursor(other.cursor_mut());
+        }
+        interleaved
+    }
+    /// Pair up the elements of two lists by position, consuming both
+    ///
+    /// The result is as long as the shorter list, and any elements left over
+    /// in the longer one are dropped.
+    pub fn zip<U>(mut self, mut other: LinkedList<U>) -> LinkedList<(T, U)> {
+        let mut zipped = LinkedList::new();
+        {
+            let mut a = self.cursor_mut();
+            let mut b = other.cursor_mut();
+            let mut dst = zipped.cursor_mut();
+            while let Some(x) = a.pop() {
+                match b.pop() {
+                    Some(y) => dst.insert_before((x, y)),
+                    None => break,
+                }
+            }
+        }
+        zipped
+    }
+    /// Apply `f` to each element, consuming the list
+    pub fn map<U, F>(mut self, mut f: F) -> LinkedList<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        let mut mapped = LinkedList::new();
+        {
+            let mut src = self.cursor_mut();
+            let mut dst = mapped.cursor_mut();
+            while let Some(el) = src.pop() {
+                dst.insert_before(f(el));
+            }
+        }
+        mapped
+    }
+    /// Apply `f` to each element along with a mutable state that starts as
+    /// `initial_state`, consuming the list
+    pub fn scan<St, U, F>(self, initial_state: St, mut f: F) -> LinkedList<U>
+    where
+        F: FnMut(&mut St, T) -> U,
+    {
+        let mut state = initial_state;
+        self.map(|el| f(&mut state, el))
+    }
+    /// Apply `f` to each element, keeping only the `Some` results
+    ///
+    /// This is the eager version of `into_iter_filter_map`.
+    pub fn filter_map<U, F>(self, f: F) -> LinkedList<U>
+    where
+        F: FnMut(T) -> Option<U>,
+    {
+        self.into_iter_filter_map(f).collect()
+    }
+    /// Replace each element with the list `f` returns for it, splicing the
+    /// lists together in order
+    pub fn flat_map<U, F>(mut self, mut f: F) -> LinkedList<U>
+    where
+        F: FnMut(T) -> LinkedList<U>,
+    {
+        let mut flat = LinkedList::new();
+        {
+            let mut src = self.cursor_mut();
+            let mut dst = flat.cursor_mut();
+            while let Some(el) = src.pop() {
+                dst.insert_list_before(f(el));
+            }
+        }
+        flat
+    }
+    /// Count the elements satisfying `f`
+    pub fn count_if<F>(&self, mut f: F) -> usize
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut c = self.cursor();
+        c.move_next();
+        let mut count = 0;
+        while let Some(current) = c.current() {
+            if f(current) {
+                count += 1;
+            }
+            c.move_next();
+        }
+        count
+    }
+    /// Returns `true` if any element satisfies `f`, stopping at the first
+    /// that does
+    pub fn any<F>(&self, f: F) -> bool
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.cursor_front().any_from_here(f)
+    }
+    /// Returns `true` if every element satisfies `f`, stopping at the first
+    /// that doesn't
+    ///
+    /// This is vacuously true for an empty list.
+    pub fn all<F>(&self, f: F) -> bool
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.cursor_front().all_from_here(f)
+    }
+    /// Combine the elements front to back into an accumulator that starts as
+    /// `init`, consuming the list
+    pub fn fold<B, F>(mut self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, T) -> B,
+    {
+        let mut acc = init;
+        let mut c = self.cursor_mut();
+        while let Some(el) = c.pop() {
+            acc = f(acc, el);
+        }
+        acc
+    }
+    /// Combine the elements front to back using the first as the initial
+    /// accumulator, consuming the list
+    ///
+    /// Returns `None` if the list is empty.
+    pub fn reduce<F>(mut self, f: F) -> Option<T>
+    where
+        F: FnMut(T, T) -> T,
+    {
+        let first = self.pop_front()?;
+        Some(self.fold(first, f))
+    }
+    /// Returns the largest element, consuming the list
+    ///
+    /// As with `Iterator::max`, the last of several equal maximums is
+    /// returned. Since this shadows `Ord::max` on lists, comparing two lists
+    /// needs `Ord::max(a, b)`.
+    pub fn max(self) -> Option<T>
+    where
+        T: Ord,
+    {
+        self.reduce(|max, el| if el >= max { el } else { max })
+    }
+    /// Returns the smallest element, consuming the list
+    ///
+    /// As with `Iterator::min`, the first of several equal minimums is
+    /// returned.
+    pub fn min(self) -> Option<T>
+    where
+        T: Ord,
+    {
+        self.reduce(|min, el| if el < min { el } else { min })
+    }
+    /// Returns the element with the largest key, or the last of them if
+    /// several are equal
+    pub fn max_by_key<K, F>(self, mut f: F) -> Option<T>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.fold(None, |max, el| {
+            let key = f(&el);
+            match max {
+                Some((max_key, max)) if key < max_key => Some((max_key, max)),
+                _ => Some((key, el)),
+            }
+        })
+        .map(|(_, el)| el)
+    }
+    /// Returns the element with the smallest key, or the first of them if
+    /// several are equal
+    pub fn min_by_key<K, F>(self, mut f: F) -> Option<T>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.fold(None, |min, el| {
+            let key = f(&el);
+            match min {
+                Some((min_key, min)) if min_key <= key => Some((min_key, min)),
+                _ => Some((key, el)),
+            }
+        })
+        .map(|(_, el)| el)
+    }
+    /* other list methods go here */
+}
+
+impl<K: Ord, V> LinkedList<(K, V)> {
+    /// Build a list of pairs sorted by key
+    ///
+    /// The sort is stable, so pairs with equal keys keep their input order.
+    pub fn from_ordered_pairs<I>(pairs: I) -> LinkedList<(K, V)>
+    where
+        I: IntoIterator<Item = (K, V)>,
+    {
+        let mut pairs: Vec<_> = pairs.into_iter().collect();
+        pairs.sort_by(|a, b| a.0.cmp(&b.0));
+        pairs.into_iter().collect()
+    }
+}
+
+impl<A, B> LinkedList<(A, B)> {
+    /// Split a list of pairs into a list of first elements and a list of
+    /// second elements, keeping their order
+    pub fn unzip(mut self) -> (LinkedList<A>, LinkedList<B>) {
+        let mut firsts = LinkedList::new();
+        let mut seconds = LinkedList::new();
+        {
+            let mut src = self.cursor_mut();
+            let mut first_cursor = firsts.cursor_mut();
+            let mut second_cursor = seconds.cursor_mut();
+            while let Some((a, b)) = src.pop() {
+                first_cursor.insert_before(a);
+                second_cursor.insert_before(b);
+            }
+        }
+        (firsts, seconds)
+    }
+}
+
+impl<T, E> LinkedList<Result<T, E>> {
+    /// Split the list into its `Ok` values and its `Err` values, keeping the
+    /// original order within each
+    pub fn flatten_results(mut self) -> (LinkedList<T>, LinkedList<E>) {
+        let mut oks = LinkedList::new();
+        let mut errs = LinkedList::new();
+        {
+            let mut src = self.cursor_mut();
+            let mut ok_cursor = oks.cursor_mut();
+            let mut err_cursor = errs.cursor_mut();
+            while let Some(el) = src.pop() {
+                match el {
+                    Ok(x) => ok_cursor.insert_before(x),
+                    Err(e) => err_cursor.insert_before(e),
+                }
+            }
+        }
+        (oks, errs)
+    }
+}
+
+impl<T> LinkedList<LinkedList<T>> {
+    /// Concatenate the inner lists in order, relinking their nodes into a
+    /// single list
+    pub fn flatten(mut self) -> LinkedList<T> {
+        let mut flat = LinkedList::new();
+        {
+            let mut src = self.cursor_mut();
+            while let Some(mut inner) = src.pop() {
+                flat.append(&mut inner);
+            }
+        }
+        flat
+    }
+}
+
+impl<T: fmt::Display> LinkedList<T> {
+    /// Render the list in Graphviz DOT format
+    ///
+    /// Every element becomes a box, with solid edges following `next`
+    /// pointers and dashed edges following `prev` pointers.
+    pub fn to_graphviz(&self) -> String {
+        use std::fmt::Write;
+
+        let mut dot = String::from("digraph LinkedList {\n    node [shape=box];\n");
+        let mut c = self.cursor();
+        c.move_next();
+        let mut index = 0;
+        while let Some(el) = c.current() {
+            let label = el.to_string().replace('\\', "\\\\").replace('"', "\\\"");
+            writeln!(dot, "    n{} [label=\"{}\"];", index, label).unwrap();
+            if index > 0 {
+                writeln!(dot, "    n{} -> n{};", index - 1, index).unwrap();
+                writeln!(dot, "    n{} -> n{} [style=dashed];", index, index - 1).unwrap();
+            }
+            c.move_next();
+            index += 1;
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+impl LinkedList<f64> {
+    /// Average every run of `window` consecutive elements
+    ///
+    /// A compensated running sum is kept as the window slides, so the
+    /// `len - window + 1` averages are computed in a single pass without
+    /// losing precision when the elements differ wildly in magnitude.
+    /// Infinities and NaNs are counted rather than summed, so they only affect
+    /// the averages of the windows containing them.
+    ///
+    /// # Panics
+    /// Panics if `window` is zero
+    pub fn window_average(&self, window: usize) -> LinkedList<f64> {
+        // The elements of the current window
+        struct Tally {
+            // Neumaier's variant of Kahan summation over the finite elements,
+            // with the lost low-order bits of `sum` accumulated in `comp`
+            sum: f64,
+            comp: f64,
+            // Each element is scaled by this power of two before it is summed,
+            // which is exact and keeps the sum from overflowing
+            scale: f64,
+            nans: usize,
+            infs: usize,
+            neg_infs: usize,
+        }
+        impl Tally {
+            fn add(&mut self, x: f64) {
+                if x.is_nan() {
+                    self.nans += 1;
+                } else if x == f64::INFINITY {
+                    self.infs += 1;
+                } else if x == f64::NEG_INFINITY {
+                    self.neg_infs += 1;
+                } else {
+                    self.sum_finite(x * self.scale);
+                }
+            }
+            fn remove(&mut self, x: f64) {
+                if x.is_nan() {
+                    self.nans -= 1;
+                } else if x == f64::INFINITY {
+                    self.infs -= 1;
+                } else if x == f64::NEG_INFINITY {
+                    self.neg_infs -= 1;
+                } else {
+                    self.sum_finite(-x * self.scale);
+                }
+            }
+            fn sum_finite(&mut self, x: f64) {
+                let t = self.sum + x;
+                if self.sum.abs() >= x.abs() {
+                    self.comp += (self.sum - t) + x;
+                } else {
+                    self.comp += (x - t) + self.sum;
+                }
+                self.sum = t;
+            }
+            fn average(&self, window: usize) -> f64 {
+                if self.nans > 0 || (self.infs > 0 && self.neg_infs > 0) {
+                    f64::NAN
+                } else if self.infs > 0 {
+                    f64::INFINITY
+                } else if self.neg_infs > 0 {
+                    f64::NEG_INFINITY
+                } else {
+                    // both sides are scaled by the same power of two, so this
+                    // rounds just like the unscaled division would
+                    (self.sum + self.comp) / (window as f64 * self.scale)
+                }
+            }
+        }
+
+        assert!(window != 0, "window must be non-zero");
+        let mut averages = LinkedList::new();
+        {
+            let mut out = averages.cursor_mut();
+            let mut front = self.cursor();
+            let mut back = self.cursor();
+            front.move_next();
+            back.move_next();
+
+            let mut tally = Tally {
+                sum: 0.0,
+                comp: 0.0,
+                scale: 1.0 / window.next_power_of_two() as f64,
+                nans: 0,
+                infs: 0,
+                neg_infs: 0,
+            };
+            let mut count = 0;
+            while let Some(&x) = front.current() {
+                tally.add(x);
+                front.move_next();
+                if count < window {
+                    count += 1;
+                } else {
+                    // `back` trails `front` by exactly `window` elements
+                    tally.remove(*back.current().unwrap());
+                    back.move_next();
+                }
+                if count == window {
+                    out.insert_before(tally.average(window));
+                }
+            }
+        }
+        averages
+    }
+}
+
+impl<T> LinkedList<Option<Option<T>>> {
+    /// Flatten both layers of `Option`, keeping only the `Some(Some(_))`
+    /// elements
+    pub fn transpose(mut self) -> LinkedList<T> {
+        let mut flat = LinkedList::new();
+        {
+            let mut src = self.cursor_mut();
+            let mut dst = flat.cursor_mut();
+            while let Some(el) = src.pop() {
+                if let Some(Some(x)) = el {
+                    dst.insert_before(x);
+                }
+            }
+        }
+        flat
+    }
+}
+
+impl<T> Drop for LinkedList<T> {
+    fn drop(&mut self) {
+        let mut c = self.cursor_mut();
+        while c.pop().is_some() {}
+    }
+}
+
+// The list owns its nodes exclusively, just like a chain of `Box`es would, so
+// it can be sent or shared whenever its elements can
+unsafe impl<T: Send> Send for LinkedList<T> {}
+unsafe impl<T: Sync> Sync for LinkedList<T> {}
+
+impl<T: fmt::Debug> fmt::Debug for LinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut t = f.debug_list();
+        let mut c = self.cursor();
+        c.move_next();
+        while let Some(e) = c.current() {
+            t.entry(&e);
+            c.move_next();
+        }
+
+        t.finish()
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for LinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("[")?;
+        let mut c = self.cursor();
+        c.move_next();
+        while let Some(e) = c.current() {
+            fmt::Display::fmt(e, f)?;
+            c.move_next();
+            if c.current().is_some() {
+                f.write_str(", ")?;
+            }
+        }
+        f.write_str("]")
+    }
+}
+
+impl<T: Clone> Clone for LinkedList<T> {
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T: PartialEq> PartialEq for LinkedList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for LinkedList<T> {}
+
+impl<T: PartialOrd> PartialOrd for LinkedList<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T: Ord> Ord for LinkedList<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<T: Hash> Hash for LinkedList<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+        for el in self {
+            el.hash(state);
+        }
+    }
+}
+
+/// Indexing walks the list from the head, so takes O(n) time
+impl<T> Index<usize> for LinkedList<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        self.get(index).unwrap_or_else(|| {
+            panic!(
+                "index {} out of bounds for list of length {}",
+                index, self.len
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<usize> for LinkedList<T> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        let len = self.len;
+        self.get_mut(index).unwrap_or_else(|| {
+            panic!(
+                "index {} out of bounds for list of length {}",
+                index, len
+            )
+        })
+    }
+}
+
+impl<T> FromIterator<T> for LinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> LinkedList<T> {
+        let mut list = LinkedList::new();
+        {
+            let mut cursor = list.cursor_mut();
+            for el in iter {
+                cursor.insert_before(el);
+            }
+        }
+        list
+    }
+}
+
+impl<T> From<Vec<T>> for LinkedList<T> {
+    fn from(vec: Vec<T>) -> LinkedList<T> {
+        vec.into_iter().collect()
+    }
+}
+
+impl<T> From<LinkedList<T>> for Vec<T> {
+    fn from(list: LinkedList<T>) -> Vec<T> {
+        list.into_iter().collect()
+    }
+}
+
+impl<T> From<VecDeque<T>> for LinkedList<T> {
+    fn from(deque: VecDeque<T>) -> LinkedList<T> {
+        deque.into_iter().collect()
+    }
+}
+
+impl<T> From<LinkedList<T>> for VecDeque<T> {
+    fn from(list: LinkedList<T>) -> VecDeque<T> {
+        list.into_iter().collect()
+    }
+}
+
+impl<T> From<std::collections::LinkedList<T>> for LinkedList<T> {
+    fn from(list: std::collections::LinkedList<T>) -> LinkedList<T> {
+        list.into_iter().collect()
+    }
+}
+
+impl<T> From<LinkedList<T>> for std::collections::LinkedList<T> {
+    fn from(list: LinkedList<T>) -> std::collections::LinkedList<T> {
+        list.into_iter().collect()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for LinkedList<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for LinkedList<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ListVisitor<T>(PhantomData<T>);
+
+        impl<'de, T: serde::Deserialize<'de>> serde::de::Visitor<'de> for ListVisitor<T> {
+            type Value = LinkedList<T>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a sequence")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<LinkedList<T>, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let mut list = LinkedList::new();
+                {
+                    let mut cursor = list.cursor_mut();
+                    while let Some(el) = seq.next_element()? {
+                        cursor.insert_before(el);
+                    }
+                }
+                Ok(list)
+            }
+        }
+
+        deserializer.deserialize_seq(ListVisitor(PhantomData))
+    }
+}
+
+impl<T> IntoIterator for LinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter { list: self }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a LinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut LinkedList<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+/// Identifies a node of a `LinkedList`
+///
+/// A handle doesn't borrow its list and is never dereferenced, so it stays
+/// safe to use after its node has been removed. It may then match whatever
+/// node is later allocated at the same address, though, so it is only
+/// meaningful while its node is still in a list.
+pub struct NodeHandle<T> {
+    node: NonNull<Node<T>>,
+}
+
+impl<T> Clone for NodeHandle<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for NodeHandle<T> {}
+
+/// An Immutable look into a `LinkedList` that can be moved back and forth
+///
+/// A cursor borrows its list, so it can't outlive it:
+///
+/// ```compile_fail
+/// # extern crate list_cursors;
+/// # use list_cursors::LinkedList;
+/// let cursor = {
+///     let list = LinkedList::from(vec![1, 2, 3]);
+///     list.cursor_front()
+/// };
+/// ```
+///
+/// Like `&LinkedList<T>` it is covariant in both `'list` and `T`, while
+/// `CursorMut` is only covariant in `'list`. Otherwise a `CursorMut` could be
+/// used to store short-lived references in a list that outlives them:
+///
+/// ```compile_fail
+/// # extern crate list_cursors;
+/// # use list_cursors::CursorMut;
+/// fn shorten<'a>(c: CursorMut<'a, &'static str>) -> CursorMut<'a, &'a str> {
+///     c
+/// }
+/// ```
+pub struct Cursor<'list, T: 'list> {
+    current: Option<NonNull<Node<T>>>,
+    list: &'list LinkedList<T>,
+}
+
+// A `Cursor` only hands out shared references, so it is as thread safe as
+// `&LinkedList<T>`
+unsafe impl<'list, T: Sync> Send for Cursor<'list, T> {}
+unsafe impl<'list, T: Sync> Sync for Cursor<'list, T> {}
+
+impl<'list, T> Cursor<'list, T> {
+    fn next_node(&self) -> Option<NonNull<Node<T>>> {
+        self.current
+            .map_or(self.list.head, |node| unsafe { node.as_ref().next })
+    }
+    fn prev_node(&self) -> Option<NonNull<Node<T>>> {
+        self.current
+            .map_or(self.list.tail, |node| unsafe { node.as_ref().prev })
+    }
+    /// Move to the subsequent element of the list if it exists or the empty
+    /// element
+    pub fn move_next(&mut self) {
+        self.current = self.next_node()
+    }
+    /// Move to the previous element of the list
+    pub fn move_prev(&mut self) {
+        self.current = self.prev_node();
+    }
+    /// Move forward up to `n` elements, stopping early at the empty element,
+    /// and return how many moves were made
+    pub fn advance_by(&mut self, n: usize) -> usize {
+        if self.list.len == 0 {
+            return 0;
+        }
+        let mut steps = 0;
+        while steps < n {
+            self.move_next();
+            steps += 1;
+            if self.current.is_none() {
+                break;
+            }
+        }
+        steps
+    }
+    /// Move backward up to `n` elements, stopping early at the empty element,
+    /// and return how many moves were made
+    pub fn retreat_by(&mut self, n: usize) -> usize {
+        if self.list.len == 0 {
+            return 0;
+        }
+        let mut steps = 0;
+        while steps < n {
+            self.move_prev();
+            steps += 1;
+            if self.current.is_none() {
+                break;
+            }
+        }
+        steps
+    }
+    /// Move to the subsequent element if it satisfies `f`, returning whether
+    /// the cursor moved
+    ///
+    /// The cursor never moves onto the empty element.
+    pub fn move_next_if<F>(&mut self, f: F) -> bool
+    where
+        F: FnOnce(&T) -> bool,
+    {
+        let step = match self.peek() {
+            Some(el) => f(el),
+            None => false,
+        };
+        if step {
+            self.move_next();
+        }
+        step
+    }
+    /// Move to the previous element if it satisfies `f`, returning whether the
+    /// cursor moved
+    ///
+    /// The cursor never moves onto the empty element.
+    pub fn move_prev_if<F>(&mut self, f: F) -> bool
+    where
+        F: FnOnce(&T) -> bool,
+    {
+        let step = match self.peek_before() {
+            Some(el) => f(el),
+            None => false,
+        };
+        if step {
+            self.move_prev();
+        }
+        step
+    }
+
+    /// Get the current element
+    pub fn current(&self) -> Option<&'list T> {
+        self.current.map(|node| unsafe {
+            // Need an unbound lifetime to get 'list
+            let node = &*node.as_ptr();
+            &node.element
+        })
+    }
+    /// Get the following element
+    pub fn peek(&self) -> Option<&'list T> {
+        self.next_node().map(|next_node| unsafe {
+            let next_node = &*next_node.as_ptr();
+            &next_node.element
+        })
+    }
+    /// Get the previous element
+    pub fn peek_before(&self) -> Option<&'list T> {
+        self.prev_node().map(|prev_node| unsafe {
+            let prev_node = &*prev_node.as_ptr();
+            &prev_node.element
+        })
+    }
+    /// Get the element `n` places after the cursor without moving it, so that
+    /// `peek_next_nth(0)` is the same as `current`
+    pub fn peek_next_nth(&self, n: usize) -> Option<&'list T> {
+        let mut node = self.current;
+        for i in 0..n {
+            node = if i == 0 {
+                self.next_node()
+            } else {
+                node.and_then(|node| unsafe { node.as_ref().next })
+            };
+        }
+        node.map(|node| unsafe {
+            let node = &*node.as_ptr();
+            &node.element
+        })
+    }
+    /// Get the element `n` places before the cursor without moving it, so that
+    /// `peek_prev_nth(0)` is the same as `current`
+    pub fn peek_prev_nth(&self, n: usize) -> Option<&'list T> {
+        let mut node = self.current;
+        for i in 0..n {
+            node = if i == 0 {
+                self.prev_node()
+            } else {
+                node.and_then(|node| unsafe { node.as_ref().prev })
+            };
+        }
+        node.map(|node| unsafe {
+            let node = &*node.as_ptr();
+            &node.element
+        })
+    }
+    /// Get the element `n + 1` places before the cursor, so that `nth_back(0)`
+    /// is the same as `peek_before`
+    pub fn nth_back(&self, n: usize) -> Option<&'list T> {
+        let mut node = self.prev_node();
+        for _ in 0..n {
+            node = node.and_then(|node| unsafe { node.as_ref().prev });
+        }
+        node.map(|node| unsafe {
+            let node = &*node.as_ptr();
+            &node.element
+        })
+    }
+
+    /// Move three cursors forward together, returning their new elements
+    ///
+    /// If any of the cursors has no following element then none of them are
+    /// moved and `None` is returned.
+    pub fn zip3<'u, 'v, U, V>(
+        a: &mut Cursor<'list, T>,
+        b: &mut Cursor<'u, U>,
+        c: &mut Cursor<'v, V>,
+    ) -> Option<(&'list T, &'u U, &'v V)> {
+        match (a.peek(), b.peek(), c.peek()) {
+            (Some(x), Some(y), Some(z)) => {
+                a.move_next();
+                b.move_next();
+                c.move_next();
+                Some((x, y, z))
+            }
+            _ => None,
+        }
+    }
+
+    /// Check whether every element from the current one to the tail satisfies
+    /// `pred`
+    ///
+    /// This is vacuously true when the cursor is at the empty element.
+    pub fn all_from_here<F>(&self, mut pred: F) -> bool
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut node = self.current;
+        while let Some(n) = node {
+            let n = unsafe { &*n.as_ptr() };
+            if !pred(&n.element) {
+                return false;
+            }
+            node = n.next;
+        }
+        true
+    }
+    /// Check whether any element from the current one to the tail satisfies
+    /// `pred`
+    pub fn any_from_here<F>(&self, mut pred: F) -> bool
+    where
+        F: FnMut(&T) -> bool,
+    {
+        !self.all_from_here(|el| !pred(el))
+    }
+
+    /// Collect the elements from the current one to the tail, or every
+    /// element of the list if the cursor is at the empty element
+    ///
+    /// The cursor doesn't move.
+    pub fn collect_remaining(&self) -> Vec<&'list T> {
+        let mut items = Vec::new();
+        let mut node = self.current.or(self.list.head);
+        while let Some(n) = node {
+            let n = unsafe { &*n.as_ptr() };
+            items.push(&n.element);
+            node = n.next;
+        }
+        items
+    }
+    /// Collect the elements from the current one back to the head, or every
+    /// element of the list in reverse if the cursor is at the empty element
+    pub fn collect_remaining_rev(&self) -> Vec<&'list T> {
+        let mut items = Vec::new();
+        let mut node = self.current.or(self.list.tail);
+        while let Some(n) = node {
+            let n = unsafe { &*n.as_ptr() };
+            items.push(&n.element);
+            node = n.prev;
+        }
+        items
+    }
+}
+
+/// Iterating a cursor moves it forward one element at a time, yielding each
+/// element it lands on
+///
+/// Iteration ends when the cursor reaches the empty element. The cursor isn't
+/// consumed, so it can still be used afterwards, and iterating it again starts
+/// over from the head.
+impl<'list, T> Iterator for Cursor<'list, T> {
+    type Item = &'list T;
+
+    fn next(&mut self) -> Option<&'list T> {
+        self.move_next();
+        self.current()
+    }
+}
+
+/// A mutable view into a `LinkedList` that can be used to edit the collection
+pub struct CursorMut<'list, T: 'list> {
+    current: Option<NonNull<Node<T>>>,
+    list: &'list mut LinkedList<T>,
+    current_len: usize,
+}
+
+// A `CursorMut` holds `&mut LinkedList<T>` and only reaches the nodes through
+// it, so it is `Send`/`Sync` exactly when that reference is
+unsafe impl<'list, T: Send> Send for CursorMut<'list, T> {}
+unsafe impl<'list, T: Sync> Sync for CursorMut<'list, T> {}
+
+impl<'list, T> CursorMut<'list, T> {
+    fn next_node(&self) -> Option<NonNull<Node<T>>> {
+        self.current
+            .map_or(self.list.head, |node| unsafe { node.as_ref().next })
+    }
+    fn prev_node(&self) -> Option<NonNull<Node<T>>> {
+        self.current
+            .map_or(self.list.tail, |node| unsafe { node.as_ref().prev })
+    }
+    // `current_len` is in the range 0...self.list.len at all times
+    fn inc_len(&mut self) {
+        self.current_len += 1;
+        self.current_len %= self.list.len + 1;
+    }
+    fn dec_len(&mut self) {
+        self.current_len += self.list.len;
+        self.current_len %= self.list.len + 1;
+    }
+
+    /// Recompute the cursor's position by walking back to the head of the
+    /// list
+    ///
+    /// This is only needed to recover from pointer manipulation that has left
+    /// the cursor's recorded position out of date, and takes O(n) time.
+    pub fn normalize_current_len(&mut self) {
+        let mut len = 0;
+        let mut node = self.current;
+        while let Some(n) = node {
+            len += 1;
+            node = unsafe { n.as_ref().prev };
+        }
+        self.current_len = len;
+    }
+
+    /// Move to the subsequent element of the list if it exists or the empty
+    /// element
+    pub fn move_next(&mut self) {
+        self.inc_len();
+        self.current = self.next_node()
+    }
+    /// Move to the previous element of the list
+    pub fn move_prev(&mut self) {
+        self.dec_len();
+        self.current = self.prev_node()
+    }
+    /// Move forward up to `n` elements, stopping early at the empty element,
+    /// and return how many moves were made
+    pub fn advance_by(&mut self, n: usize) -> usize {
+        if self.list.len == 0 {
+            return 0;
+        }
+        let mut steps = 0;
+        while steps < n {
+            self.move_next();
+            steps += 1;
+            if self.current.is_none() {
+                break;
+            }
+        }
+        steps
+    }
+    /// Move backward up to `n` elements, stopping early at the empty element,
+    /// and return how many moves were made
+    pub fn retreat_by(&mut self, n: usize) -> usize {
+        if self.list.len == 0 {
+            return 0;
+        }
+        let mut steps = 0;
+        while steps < n {
+            self.move_prev();
+            steps += 1;
+            if self.current.is_none() {
+                break;
+            }
+        }
+        steps
+    }
+    /// Move to the subsequent element if it satisfies `f`, returning whether
+    /// the cursor moved
+    ///
+    /// The cursor never moves onto the empty element.
+    pub fn move_next_if<F>(&mut self, f: F) -> bool
+    where
+        F: FnOnce(&T) -> bool,
+    {
+        let step = match self.next_node() {
+            Some(node) => f(unsafe { &node.as_ref().element }),
+            None => false,
+        };
+        if step {
+            self.move_next();
+        }
+        step
+    }
+    /// Move to the previous element if it satisfies `f`, returning whether the
+    /// cursor moved
+    ///
+    /// The cursor never moves onto the empty element.
+    pub fn move_prev_if<F>(&mut self, f: F) -> bool
+    where
+        F: FnOnce(&T) -> bool,
+    {
+        let step = match self.prev_node() {
+            Some(node) => f(unsafe { &node.as_ref().element }),
+            None => false,
+        };
+        if step {
+            self.move_prev();
+        }
+        step
+    }
+    /// Move to the element at `index`, walking from whichever end of the list
+    /// is closer
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds
+    pub fn seek_to(&mut self, index: usize) {
+        let len = self.list.len;
+        assert!(
+            index < len,
+            "index {} out of bounds for list of length {}",
+            index,
+            len
+        );
+        let mut node;
+        unsafe {
+            if index < len / 2 {
+                node = self.list.head.unwrap();
+                for _ in 0..index {
+                    node = node.as_ref().next.unwrap();
+                }
+            } else {
+                node = self.list.tail.unwrap();
+                for _ in index + 1..len {
+                    node = node.as_ref().prev.unwrap();
+                }
+            }
+        }
+        self.current = Some(node);
+        self.current_len = index + 1;
+    }
+    /// Move to the node identified by `handle`, returning whether it is in
+    /// the list
+    ///
+    /// The list is searched from the head, so this takes O(n) time. The
+    /// cursor doesn't move if the node isn't found.
+    pub fn seek_to_handle(&mut self, handle: NodeHandle<T>) -> bool {
+        let mut node = self.list.head;
+        let mut len = 1;
+        while let Some(n) = node {
+            if n == handle.node {
+                self.current = Some(n);
+                self.current_len = len;
+                return true;
+            }
+            node = unsafe { n.as_ref().next };
+            len += 1;
+        }
+        false
+    }
+    /// Move to the first element, or the empty element if the list is empty
+    pub fn move_to_front(&mut self) {
+        self.current = self.list.head;
+        self.current_len = if self.list.len == 0 { 0 } else { 1 };
+    }
+    /// Move to the last element, or the empty element if the list is empty
+    pub fn move_to_back(&mut self) {
+        self.current = self.list.tail;
+        self.current_len = self.list.len;
+    }
+    /// Move to the subsequent element if the current element satisfies
+    /// `pred`, returning whether the cursor moved
+    ///
+    /// The cursor never moves off of the empty element.
+    pub fn step_over_if<F>(&mut self, pred: F) -> bool
+    where
+        F: FnOnce(&T) -> bool,
+    {
+        let step = match self.current {
+            Some(node) => pred(unsafe { &node.as_ref().element }),
+            None => false,
+        };
+        if step {
+            self.move_next();
+        }
+        step
+    }
+
+    /// Get the current element
+    pub fn current(&mut self) -> Option<&mut T> {
+        self.current.map(|node| unsafe {
+            // Need an unbound lifetime to get same lifetime as self
+            let node = &mut *node.as_ptr();
+            &mut node.element
+        })
+    }
+    /// Get the next element
+    pub fn peek(&mut self) -> Option<&mut T> {
+        self.next_node().map(|next_node| unsafe {
+            let next_node = &mut *next_node.as_ptr();
+            &mut next_node.element
+        })
+    }
+    /// Get the previous element
+    ///
+    /// This borrows the cursor mutably, so two references to the same element
+    /// can't be taken at once:
+    ///
+    /// ```compile_fail
+    /// # extern crate list_cursors;
+    /// # use list_cursors::LinkedList;
+    /// let mut list = LinkedList::from(vec![1]);
+    /// let mut cursor = list.cursor_mut();
+    /// let a = cursor.peek_before().unwrap();
+    /// let b = cursor.peek_before().unwrap();
+    /// *a += *b;
+    /// ```
+    pub fn peek_before(&mut self) -> Option<&mut T> {
+        self.prev_node().map(|prev_node| unsafe {
+            let prev_node = &mut *prev_node.as_ptr();
+            &mut prev_node.element
+        })
+    }
+
+    /// Replace every element after the cursor with a clone of `value`
+    pub fn fill_remaining(&mut self, value: &T)
+    where
+        T: Clone,
+    {
+        let mut node = self.next_node();
+        while let Some(mut n) = node {
+            unsafe {
+                n.as_mut().element = value.clone();
+                node = n.as_ref().next;
+            }
+        }
+    }
+
+    /// Negate every element from the current one to the tail, or every
+    /// element of the list if the cursor is at the empty element
+    pub fn invert_all_remaining(&mut self)
+    where
+        T: Neg<Output = T> + Copy,
+    {
+        let mut node = self.current.or(self.list.head);
+        while let Some(mut n) = node {
+            unsafe {
+                let el = &mut n.as_mut().element;
+                *el = -*el;
+                node = n.as_ref().next;
+            }
+        }
+    }
+
+    /// Swap the current element with the next one, returning whether there
+    /// was an element to swap with
+    ///
+    /// Only the elements are swapped, so the nodes stay where they are and
+    /// the cursor stays on the same node, now holding the next element's
+    /// value. Nothing happens at the empty element or at the tail.
+    pub fn swap_with_next(&mut self) -> bool {
+        match (self.current, self.next_node()) {
+            (Some(current), Some(next)) => unsafe {
+                std::ptr::swap(
+                    &mut (*current.as_ptr()).element,
+                    &mut (*next.as_ptr()).element,
+                );
+                true
+            },
+            _ => false,
+        }
+    }
+    /// Swap the current element with the previous one, returning whether
+    /// there was an element to swap with
+    ///
+    /// As with `swap_with_next`, only the elements are swapped.
+    pub fn swap_with_prev(&mut self) -> bool {
+        match (self.current, self.prev_node()) {
+            (Some(current), Some(prev)) => unsafe {
+                std::ptr::swap(
+                    &mut (*current.as_ptr()).element,
+                    &mut (*prev.as_ptr()).element,
+                );
+                true
+            },
+            _ => false,
+        }
+    }
+
+    /// Get an immutable cursor at the current element
+    ///
+    /// The returned cursor borrows this one, so the list can't be edited
+    /// while it is alive:
+    ///
+    /// ```compile_fail
+    /// # extern crate list_cursors;
+    /// # use list_cursors::LinkedList;
+    /// let mut list = LinkedList::from(vec![1, 2, 3]);
+    /// let mut cursor = list.cursor_front_mut();
+    /// let first = cursor.as_cursor().current();
+    /// cursor.remove_current();
+    /// assert_eq!(first, Some(&1));
+    /// ```
+    pub fn as_cursor(&self) -> Cursor<'_, T> {
+        Cursor {
+            current: self.current,
+            list: self.list,
+        }
+    }
+
+    /// Provides an iterator that moves the cursor forward, yielding mutable
+    /// references to each element it lands on
+    ///
+    /// Iteration ends at the empty element, where the cursor is left.
+    pub fn iter_mut(&mut self) -> CursorIterMut<'_, 'list, T> {
+        CursorIterMut {
+            cursor: self,
+            forward: true,
+            done: false,
+        }
+    }
+    /// Provides an iterator that moves the cursor backward, yielding mutable
+    /// references to each element it lands on
+    pub fn rev_iter_mut(&mut self) -> CursorIterMut<'_, 'list, T> {
+        CursorIterMut {
+            cursor: self,
+            forward: false,
+            done: false,
+        }
+    }
+
+    // Now the list editing operations
+
+    /// Check whether `insert`ing `item` would keep a sorted list sorted, that
+    /// is whether `item` falls between the current and next elements
+    ///
+    /// The empty element acts as a boundary that any item may be placed
+    /// against.
+    ///
+    /// Since `insert` places the item after the cursor, this compares against
+    /// the current and next elements rather than `peek_before()` and `peek()`.
+    /// It only reads the list, so it takes `&self` instead of `&mut self`.
+    pub fn ensure_sorted_before_insert(&self, item: &T) -> bool
+    where
+        T: Ord,
+    {
+        let after_current = match self.current {
+            None => true,
+            Some(node) => unsafe { node.as_ref().element <= *item },
+        };
+        let before_next = match self.next_node() {
+            None => true,
+            Some(node) => unsafe { *item <= node.as_ref().element },
+        };
+        after_current && before_next
+    }
+
+    /// Insert `item` after the cursor
+    pub fn insert(&mut self, item: T) {
+        let mut node = box Node::new(item);
+        node.prev = self.current;
+        node.next = self.next_node();
+
+        unsafe {
+            let node_ptr = Some(Box::into_raw_non_null(node));
+            match self.next_node() {
+                None => self.list.tail = node_ptr,
+                Some(mut next) => next.as_mut().prev = node_ptr,
+            }
+            match self.current {
+                None => self.list.head = node_ptr,
+                Some(mut prev) => prev.as_mut().next = node_ptr,
+            }
+        }
+        self.list.len += 1;
+    }
+    /// Insert `item` before the cursor
+    pub fn insert_before(&mut self, item: T) {
+        let mut node = box Node::new(item);
+        node.prev = self.prev_node();
+        node.next = self.current;
+
+        unsafe {
+            let node_ptr = Some(Box::into_raw_non_null(node));
+            match self.prev_node() {
+                None => self.list.head = node_ptr,
+                Some(mut next) => next.as_mut().next = node_ptr,
+            }
+            match self.current {
+                None => self.list.tail = node_ptr,
+                Some(mut prev) => prev.as_mut().prev = node_ptr,
+            }
+        }
+        self.list.len += 1;
+        self.inc_len();
+    }
+
+    /// Insert the items of `iter` between the current element and the next,
+    /// in order
+    ///
+    /// The new nodes are linked to each other first and then spliced into
+    /// the list all at once.
+    pub fn insert_iter<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut items: LinkedList<T> = iter.into_iter().collect();
+        self.absorb_cursor(items.cursor_mut());
+    }
+
+    /// Insert `list` between the current element and the next
+    pub fn insert_list(&mut self, list: LinkedList<T>) {
+        match (list.head, list.tail) {
+            (Some(mut head), Some(mut tail)) => unsafe {
+                head.as_mut().prev = self.current;
+                tail.as_mut().next = self.next_node();
+            },
+            //splicing in an empty list should be a no-op
+            (None, None) => return,
+            _ => unreachable!(),
+        }
+        unsafe {
+            match self.next_node() {
+                None => self.list.tail = list.tail,
+                Some(mut next) => next.as_mut().prev = list.tail,
+            }
+            match self.current {
+                None => self.list.head = list.head,
+                Some(mut prev) => prev.as_mut().next = list.head,
+            }
+        }
+        self.list.len += list.len;
+        // the nodes belong to `self.list` now, so `list` mustn't free them
+        std::mem::forget(list);
+    }
+
+    /// Insert `list` between the previous element and current
+    pub fn insert_list_before(&mut self, list: LinkedList<T>) {
+        match (list.head, list.tail) {
+            (Some(mut head), Some(mut tail)) => unsafe {
+                head.as_mut().prev = self.prev_node();
+                tail.as_mut().next = self.current;
+            },
+            //splicing in an empty list should be a no-op
+            (None, None) => return,
+            _ => unreachable!(),
+        }
+        unsafe {
+            match self.prev_node() {
+                None => self.list.head = list.head,
+                Some(mut next) => next.as_mut().next = list.head,
+            }
+            match self.current {
+                None => self.list.tail = list.tail,
+                Some(mut prev) => prev.as_mut().prev = list.tail,
+            }
+        }
+        self.list.len += list.len;
+        if self.current_len != 0 {
+            self.current_len += list.len;
+        }
+        std::mem::forget(list);
+    }
+
+    /// Move every element of `other`'s list in between the current element
+    /// and the next, returning how many elements were moved
+    ///
+    /// `other`'s list is left empty.
+    pub fn absorb_cursor(&mut self, other: CursorMut<T>) -> usize {
+        let len = other.list.len;
+        other.list.len = 0;
+        match (other.list.head.take(), other.list.tail.take()) {
+            (Some(mut head), Some(mut tail)) => unsafe {
+                head.as_mut().prev = self.current;
+                tail.as_mut().next = self.next_node();
+                match self.next_node() {
+                    None => self.list.tail = Some(tail),
+                    Some(mut next) => next.as_mut().prev = Some(tail),
+                }
+                match self.current {
+                    None => self.list.head = Some(head),
+                    Some(mut prev) => prev.as_mut().next = Some(head),
+                }
+            },
+            (None, None) => return 0,
+            _ => unreachable!(),
+        }
+        self.list.len += len;
+        len
+    }
+
+    /// Clone up to `count` of the elements following `src` in between the
+    /// current element and the next, returning how many were cloned
+    ///
+    /// `src` is moved onto the last element cloned.
+    pub fn extend_from_cursor(&mut self, src: &mut CursorMut<T>, count: usize) -> usize
+    where
+        T: Clone,
+    {
+        let mut clones = LinkedList::new();
+        {
+            let mut dst = clones.cursor_mut();
+            for _ in 0..count {
+                match src.peek() {
+                    Some(el) => dst.insert_before(el.clone()),
+                    None => break,
+                }
+                src.move_next();
+            }
+        }
+        self.absorb_cursor(clones.cursor_mut())
+    }
+
+    /// Remove and return the item following the cursor
+    pub fn pop(&mut self) -> Option<T> {
+        self.next_node().map(|node| unsafe {
+            self.list.len -= 1;
+            self.current_len %= self.list.len + 1;
+
+            let node = Box::from_raw(node.as_ptr());
+            match self.current {
+                None => self.list.head = node.next,
+                Some(mut prev) => prev.as_mut().next = node.next,
+            }
+            match node.next {
+                None => self.list.tail = self.current,
+                Some(mut next) => {
+                    next.as_mut().prev = self.current;
+                }
+            }
+            Node::into_element(node)
+        })
+    }
+    /// Replace the current item with `item`, returning the old item
+    ///
+    /// Nothing is replaced at the empty element, in which case `None` is
+    /// returned.
+    pub fn replace_current(&mut self, item: T) -> Option<T> {
+        use std::mem::replace;
+
+        self.current().map(|current| replace(current, item))
+    }
+    /// Replace the current item with the result of passing it to `f`
+    ///
+    /// `f` isn't called at the empty element. If `f` panics the process is
+    /// aborted, since the current node would otherwise be left without an
+    /// element.
+    pub fn replace_current_with<F>(&mut self, f: F)
+    where
+        F: FnOnce(T) -> T,
+    {
+        struct AbortOnUnwind;
+        impl Drop for AbortOnUnwind {
+            fn drop(&mut self) {
+                std::process::abort();
+            }
+        }
+
+        if let Some(current) = self.current() {
+            unsafe {
+                let guard = AbortOnUnwind;
+                let item = std::ptr::read(current);
+                std::ptr::write(current, f(item));
+                std::mem::forget(guard);
+            }
+        }
+    }
+
+    /// Remove and return the current item, moving the cursor to the element
+    /// that followed it
+    pub fn remove_current(&mut self) -> Option<T> {
+        self.current.map(|node| unsafe {
+            self.list.len -= 1;
+
+            let node = Box::from_raw(node.as_ptr());
+            match node.prev {
+                None => self.list.head = node.next,
+                Some(mut prev) => prev.as_mut().next = node.next,
+            }
+            match node.next {
+                None => self.list.tail = node.prev,
+                Some(mut next) => next.as_mut().prev = node.prev,
+            }
+            self.current = node.next;
+            if self.current.is_none() {
+                self.current_len = 0;
+            }
+            Node::into_element(node)
+        })
+    }
+    /// Remove and return the item following the cursor, moving the cursor to
+    /// the element after the removed one
+    ///
+    /// The cursor doesn't move if there is nothing to remove.
+    pub fn advance_then_pop(&mut self) -> Option<T> {
+        let item = self.pop();
+        if item.is_some() {
+            self.move_next();
+        }
+        item
+    }
+    /// Remove up to `n` of the items following the cursor, returning them in
+    /// order
+    pub fn pop_n(&mut self, n: usize) -> Vec<T> {
+        let mut items = Vec::new();
+        while items.len() < n {
+            match self.pop() {
+                Some(item) => items.push(item),
+                None => break,
+            }
+        }
+        items
+    }
+    /// Remove every item in the list, returning them in order
+    ///
+    /// The cursor is left at the empty element.
+    pub fn collect_and_clear(&mut self) -> Vec<T> {
+        self.current = None;
+        self.current_len = 0;
+        let mut items = Vec::with_capacity(self.list.len);
+        while let Some(item) = self.pop() {
+            items.push(item);
+        }
+        items
+    }
+    /// Remove and return the item before the cursor
+    pub fn pop_prev(&mut self) -> Option<T> {
+        self.prev_node().map(|node| unsafe {
+            self.list.len -= 1;
+            self.dec_len();
+
+            let node = Box::from_raw(node.as_ptr());
+            match node.prev {
+                None => self.list.head = self.current,
+                Some(mut prev) => prev.as_mut().next = self.current,
+            }
+            match self.current {
+                None => self.list.tail = node.prev,
+                Some(mut next) => next.as_mut().prev = node.prev,
+            }
+            Node::into_element(node)
+        })
+    }
+
+    /// Rotate the `len` elements starting `start` elements after the cursor
+    /// one position to the right, so the last element of the segment becomes
+    /// the first
+    ///
+    /// Nodes are relinked rather than having their elements moved, and the
+    /// cursor stays where it is.
+    ///
+    /// # Panics
+    /// Panics if the segment extends past the end of the list
+    pub fn rotate_segment_right(&mut self, start: usize, len: usize) {
+        // segments too short to rotate are still checked, so that a bad
+        // `start` is caught however long the segment is
+        assert!(
+            start.saturating_add(len) <= self.list.len - self.current_len,
+            "segment extends past the end of the list"
+        );
+        if len < 2 {
+            return;
+        }
+        let mut first = self.next_node();
+        for _ in 0..start {
+            first = first.and_then(|node| unsafe { node.as_ref().next });
+        }
+        let mut last = first;
+        for _ in 1..len {
+            last = last.and_then(|node| unsafe { node.as_ref().next });
+        }
+        match (first, last) {
+            (Some(mut first), Some(mut last)) => unsafe {
+                // unlink `last`, which has a predecessor since `len >= 2`
+                let mut before_last = last.as_ref().prev.unwrap();
+                before_last.as_mut().next = last.as_ref().next;
+                match last.as_ref().next {
+                    None => self.list.tail = Some(before_last),
+                    Some(mut next) => next.as_mut().prev = Some(before_last),
+                }
+                // and relink it in front of `first`
+                last.as_mut().prev = first.as_ref().prev;
+                last.as_mut().next = Some(first);
+                match first.as_ref().prev {
+                    None => self.list.head = Some(last),
+                    Some(mut prev) => prev.as_mut().next = Some(last),
+                }
+                first.as_mut().prev = Some(last);
+            },
+            _ => panic!("segment extends past the end of the list"),
+        }
+    }
+
+    /// Move the current element `n` places towards the head of the list, or
+    /// to the head if fewer than `n` elements precede it
+    ///
+    /// The node is relinked rather than having its element moved, and the
+    /// cursor follows it to its new position.
+    pub fn rotate_current_backward(&mut self, n: usize) {
+        let mut cur = match self.current {
+            Some(cur) => cur,
+            None => return,
+        };
+        unsafe {
+            let mut before = cur;
+            let mut steps = 0;
+            while steps < n {
+                match before.as_ref().prev {
+                    Some(prev) => before = prev,
+                    None => break,
+                }
+                steps += 1;
+            }
+            if steps == 0 {
+                return;
+            }
+
+            // unlink `cur`, which has a predecessor since `steps > 0`
+            let mut prev = cur.as_ref().prev.unwrap();
+            prev.as_mut().next = cur.as_ref().next;
+            match cur.as_ref().next {
+                None => self.list.tail = Some(prev),
+                Some(mut next) => next.as_mut().prev = Some(prev),
+            }
+            // and relink it in front of `before`
+            cur.as_mut().prev = before.as_ref().prev;
+            cur.as_mut().next = Some(before);
+            match before.as_ref().prev {
+                None => self.list.head = Some(cur),
+                Some(mut prev) => prev.as_mut().next = Some(cur),
+            }
+            before.as_mut().prev = Some(cur);
+            self.current_len -= steps;
+        }
+    }
+
+    /// Remove the run of elements following the cursor that satisfy `pred`,
+    /// returning them as a new list
+    ///
+    /// The run ends at the first element for which `pred` returns false, which
+    /// is left in the list along with everything after it. The cursor doesn't
+    /// move.
+    pub fn split_while<F>(&mut self, mut pred: F) -> LinkedList<T>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let first = self.next_node();
+        let mut last = None;
+        let mut len = 0;
+        let mut rest = first;
+        while let Some(node) = rest {
+            let node_ref = unsafe { node.as_ref() };
+            if !pred(&node_ref.element) {
+                break;
+            }
+            last = Some(node);
+            len += 1;
+            rest = node_ref.next;
+        }
+
+        match (first, last) {
+            (Some(mut first), Some(mut last)) => unsafe {
+                match self.current {
+                    None => self.list.head = rest,
+                    Some(mut current) => current.as_mut().next = rest,
+                }
+                match rest {
+                    None => self.list.tail = self.current,
+                    Some(mut rest) => rest.as_mut().prev = self.current,
+                }
+                first.as_mut().prev = None;
+                last.as_mut().next = None;
+                self.list.len -= len;
+
+                LinkedList {
+                    head: Some(first),
+                    tail: Some(last),
+                    len,
+                    marker: PhantomData,
+                }
+            },
+            _ => LinkedList::new(),
+        }
+    }
+
+    /// Remove up to `count` of the elements following the cursor, returning
+    /// them as a new list
+    ///
+    /// If fewer than `count` elements follow the cursor then all of them are
+    /// taken. The cursor doesn't move.
+    pub fn splice_after(&mut self, count: usize) -> LinkedList<T> {
+        let mut taken = 0;
+        self.split_while(|_| {
+            taken += 1;
+            taken <= count
+        })
+    }
+
+    fn split_at(self, current: NonNull<Node<T>>, split_len: usize) -> LinkedList<T> {
+        let total_len = self.list.len;
+
+        let next = unsafe { (*current.as_ptr()).next };
+
+        if let Some(next) = next {
+            let new_head = Some(next);
+            let new_tail = self.list.tail.take();
+            let new_len = total_len - split_len;
+
+            let old_head = self.list.head;
+            let old_tail = Some(current);
+            let old_len = total_len - new_len;
+
+            unsafe {
+                (*current.as_ptr()).next = None;
+                (*next.as_ptr()).prev = None;
+            }
+
+            self.list.head = old_head;
+            self.list.tail = old_tail;
+            self.list.len = old_len;
+
+            LinkedList {
+                head: new_head,
+                tail: new_tail,
+                len: new_len,
+                marker: PhantomData,
+            }
+        } else {
+            LinkedList::new()
+        }
+    }
+
+    /// Split the list in two after the current element
+    /// The returned list consists of all elements following the current one.
+    // note: consuming the cursor is not necessary here, but it makes sense
+    // given the interface
+    pub fn split(self) -> LinkedList<T> {
+        use std::mem::replace;
+
+        match self.current {
+            None => replace(self.list, LinkedList::new()),
+
+            Some(current) => {
+                let split_len = self.current_len;
+                self.split_at(current, split_len)
+            }
+        }
+    }
+
+    /// Split the list in two before the current element
+    pub fn split_before(self) -> LinkedList<T> {
+        use std::mem::replace;
+
+        match self.current {
+            None => replace(self.list, LinkedList::new()),
+            Some(current) => match unsafe { (*current.as_ptr()).prev } {
+                None => replace(self.list, LinkedList::new()),
+                Some(prev) => {
+                    let split_len = self.current_len - 1;
+                    self.split_at(prev, split_len)
+                }
+            },
+        }
+    }
+}
+
+/// An iterator over the elements of a `LinkedList`
+///
+/// Created by `LinkedList::iter`
+pub struct Iter<'a, T: 'a> {
+    head: Option<NonNull<Node<T>>>,
+    tail: Option<NonNull<Node<T>>>,
+    len: usize,
+    marker: PhantomData<&'a Node<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.head.map(|node| unsafe {
+            // Need an unbound lifetime to get 'a
+            let node = &*node.as_ptr();
+            self.len -= 1;
+            self.head = node.next;
+            &node.element
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.tail.map(|node| unsafe {
+            let node = &*node.as_ptr();
+            self.len -= 1;
+            self.tail = node.prev;
+            &node.element
+        })
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
+
+impl<'a, T> FusedIterator for Iter<'a, T> {}
+
+impl<'a, T> Clone for Iter<'a, T> {
+    fn clone(&self) -> Self {
+        Iter { ..*self }
+    }
+}
+
+unsafe impl<'a, T: Sync> Send for Iter<'a, T> {}
+unsafe impl<'a, T: Sync> Sync for Iter<'a, T> {}
+
+/// A mutable iterator over the elements of a `LinkedList`
+///
+/// Created by `LinkedList::iter_mut`
+pub struct IterMut<'a, T: 'a> {
+    head: Option<NonNull<Node<T>>>,
+    tail: Option<NonNull<Node<T>>>,
+    len: usize,
+    marker: PhantomData<&'a mut Node<T>>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.head.map(|node| unsafe {
+            // Need an unbound lifetime to get 'a
+            let node = &mut *node.as_ptr();
+            self.len -= 1;
+            self.head = node.next;
+            &mut node.element
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<&'a mut T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.tail.map(|node| unsafe {
+            let node = &mut *node.as_ptr();
+            self.len -= 1;
+            self.tail = node.prev;
+            &mut node.element
+        })
+    }
+}
+
+impl<'a, T> ExactSizeIterator for IterMut<'a, T> {}
+
+impl<'a, T> FusedIterator for IterMut<'a, T> {}
+
+unsafe impl<'a, T: Send> Send for IterMut<'a, T> {}
+unsafe impl<'a, T: Sync> Sync for IterMut<'a, T> {}
+
+/// An owning iterator over the elements of a `LinkedList`
+///
+/// Created by `LinkedList::into_iter`. Any elements that aren't iterated over
+/// are dropped along with the iterator.
+pub struct IntoIter<T> {
+    list: LinkedList<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.list.cursor_mut().pop()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len, Some(self.list.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.list.cursor_mut().pop_prev()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> FusedIterator for IntoIter<T> {}
+
+/// A draining iterator over the elements of a `LinkedList`
+///
+/// Created by `LinkedList::drain`
+pub struct Drain<'a, T: 'a> {
+    list: &'a mut LinkedList<T>,
+}
+
+impl<'a, T> Iterator for Drain<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len, Some(self.list.len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Drain<'a, T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.list.pop_back()
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Drain<'a, T> {}
+
+impl<'a, T> FusedIterator for Drain<'a, T> {}
+
+impl<'a, T> Drop for Drain<'a, T> {
+    fn drop(&mut self) {
+        while self.list.pop_front().is_some() {}
+    }
+}
+
+/// An iterator removing the elements of a `LinkedList` that satisfy a
+/// predicate
+///
+/// Created by `LinkedList::extract_if`
+pub struct ExtractIf<'a, T: 'a, F> {
+    list: &'a mut LinkedList<T>,
+    next: Option<NonNull<Node<T>>>,
+    pred: F,
+}
+
+impl<'a, T, F> Iterator for ExtractIf<'a, T, F>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        while let Some(node) = self.next {
+            unsafe {
+                self.next = node.as_ref().next;
+                if (self.pred)(&mut (*node.as_ptr()).element) {
+                    self.list.unlink_node(node);
+                    return Some(Node::into_element(Box::from_raw(node.as_ptr())));
+                }
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.list.len))
+    }
+}
+
+impl<'a, T, F> FusedIterator for ExtractIf<'a, T, F> where F: FnMut(&mut T) -> bool {}
+
+/// An iterator splitting chunks off the front of a `LinkedList`
+///
+/// Created by `LinkedList::into_iter_chunks`
+pub struct IntoIterChunks<T> {
+    list: LinkedList<T>,
+    chunk_size: usize,
+}
+
+impl<T> Iterator for IntoIterChunks<T> {
+    type Item = LinkedList<T>;
+
+    fn next(&mut self) -> Option<LinkedList<T>> {
+        use std::mem::replace;
+
+        if self.list.len == 0 {
+            return None;
+        }
+        let rest = {
+            let mut c = self.list.cursor_mut();
+            for _ in 0..self.chunk_size {
+                if c.peek().is_none() {
+                    break;
+                }
+                c.move_next();
+            }
+            c.split()
+        };
+        Some(replace(&mut self.list, rest))
+    }
+}
+
+/// A consuming iterator that filters and maps the elements of a `LinkedList`
+///
+/// Created by `LinkedList::into_iter_filter_map`
+pub struct IntoIterFilterMap<T, F> {
+    list: LinkedList<T>,
+    f: F,
+}
+
+impl<T, B, F> Iterator for IntoIterFilterMap<T, F>
+where
+    F: FnMut(T) -> Option<B>,
+{
+    type Item = B;
+
+    fn next(&mut self) -> Option<B> {
+        let mut c = self.list.cursor_mut();
+        while let Some(el) = c.pop() {
+            if let Some(b) = (self.f)(el) {
+                return Some(b);
+            }
+        }
+        None
+    }
+}
+
+/// A consuming iterator over arrays of consecutive elements of a `LinkedList`
+///
+/// Created by `LinkedList::nwise`
+pub struct NWise<T, const N: usize> {
+    list: LinkedList<T>,
+}
+
+impl<T, const N: usize> Iterator for NWise<T, N> {
+    type Item = [T; N];
+
+    fn next(&mut self) -> Option<[T; N]> {
+        if self.list.len < N {
+            return None;
+        }
+        let mut c = self.list.cursor_mut();
+        Some(std::array::from_fn(|_| c.pop().unwrap()))
+    }
+}
+
+/// An iterator over overlapping arrays of consecutive elements of a
+/// `LinkedList`
+///
+/// Created by `LinkedList::windows`
+pub struct Windows<'a, T: 'a, const N: usize> {
+    head: Option<NonNull<Node<T>>>,
+    remaining: usize,
+    marker: PhantomData<&'a Node<T>>,
+}
+
+impl<'a, T, const N: usize> Iterator for Windows<'a, T, N> {
+    type Item = [&'a T; N];
+
+    fn next(&mut self) -> Option<[&'a T; N]> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let mut node = self.head;
+        let window = std::array::from_fn(|_| unsafe {
+            // Need an unbound lifetime to get 'a
+            let n = &*node.unwrap().as_ptr();
+            node = n.next;
+            &n.element
+        });
+        self.head = self.head.and_then(|head| unsafe { head.as_ref().next });
+        Some(window)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T, const N: usize> ExactSizeIterator for Windows<'a, T, N> {}
+
+impl<'a, T, const N: usize> FusedIterator for Windows<'a, T, N> {}
+
+unsafe impl<'a, T: Sync, const N: usize> Send for Windows<'a, T, N> {}
+unsafe impl<'a, T: Sync, const N: usize> Sync for Windows<'a, T, N> {}
+
+/// An iterator over non-overlapping chunks of the elements of a `LinkedList`
+///
+/// Created by `LinkedList::chunks`
+pub struct Chunks<'a, T: 'a> {
+    iter: Iter<'a, T>,
+    chunk_size: usize,
+}
+
+impl<'a, T> Iterator for Chunks<'a, T> {
+    type Item = Vec<&'a T>;
+
+    fn next(&mut self) -> Option<Vec<&'a T>> {
+        if self.iter.len == 0 {
+            return None;
+        }
+        Some(self.iter.by_ref().take(self.chunk_size).collect())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let chunks = self.iter.len.div_ceil(self.chunk_size);
+        (chunks, Some(chunks))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Chunks<'a, T> {}
+
+impl<'a, T> FusedIterator for Chunks<'a, T> {}
+
+/// An iterator moving a `CursorMut` through its list
+///
+/// Created by `CursorMut::iter_mut` and `CursorMut::rev_iter_mut`. The
+/// references it yields borrow the cursor, so it can't edit the list while
+/// they are alive, and iteration stops at the empty element rather than
+/// wrapping around, so no element is yielded twice.
+pub struct CursorIterMut<'a, 'list: 'a, T: 'list> {
+    cursor: &'a mut CursorMut<'list, T>,
+    forward: bool,
+    done: bool,
+}
+
+impl<'a, 'list, T> Iterator for CursorIterMut<'a, 'list, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        if self.done {
+            return None;
+        }
+        if self.forward {
+            self.cursor.move_next();
+        } else {
+            self.cursor.move_prev();
+        }
+        match self.cursor.current {
+            None => {
+                self.done = true;
+                None
+            }
+            // Need an unbound lifetime to get 'a
+            Some(node) => Some(unsafe { &mut (*node.as_ptr()).element }),
+        }
+    }
+}
+
+impl<'a, 'list, T> FusedIterator for CursorIterMut<'a, 'list, T> {}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::fmt::Debug;
+    use std::iter::FromIterator;
+
+    use super::{Cursor, CursorMut, LinkedList};
+
+    /// Counts how many times it has been dropped
+    struct DropCounter<'a>(&'a Cell<usize>);
+    impl<'a> Drop for DropCounter<'a> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    fn mut_cmp_iterator<T, I>(list: &mut LinkedList<T>, iter: I)
+    where
+        T: PartialEq + Debug,
+        I: IntoIterator<Item = T> + Clone + Iterator + DoubleEndedIterator<Item = T>,
+    {
+        {
+            let mut cursor = list.cursor_mut();
+            for i in iter.clone() {
+                cursor.move_next();
+                let mut i = i;
+                assert_eq!(cursor.current(), Some(&mut i));
+            }
+            cursor.move_next();
+            assert_eq!(cursor.current(), None);
+        }
+        {
+            let mut cursor = list.cursor_mut();
+            let iter = iter.rev();
+
+            for i in iter {
+                cursor.move_prev();
+                let mut i = i;
+                assert_eq!(cursor.current(), Some(&mut i));
+            }
+            cursor.move_prev();
+            assert_eq!(cursor.current(), None);
+        }
+    }
+
+    fn cmp_iterator<T, I>(list: &LinkedList<T>, iter: I)
+    where
+        T: PartialEq + Debug,
+        I: IntoIterator<Item = T> + Clone + Iterator + DoubleEndedIterator<Item = T>,
+    {
+        {
+            // test raw links
+            let mut cursor = list.cursor();
+            cursor.move_next();
+            while let Some(current) = cursor.current {
+                if let Some(next) = unsafe { (*current.as_ptr()).next } {
+                    assert_eq!(unsafe { (*next.as_ptr()).prev }, Some(current));
+                }
+                cursor.move_next();
+            }
+        }
+        {
+            // test forwards iteration
             let mut cursor = list.cursor();
             for i in iter.clone() {
                 cursor.move_next();
                 assert_eq!(cursor.current(), Some(&i));
             }
-            cursor.move_next();
-            assert_eq!(cursor.current(), None);
+            cursor.move_next();
+            assert_eq!(cursor.current(), None);
+        }
+        {
+            // test reverse iteration
+            let mut cursor = list.cursor();
+            let iter = iter.rev();
+
+            for i in iter {
+                cursor.move_prev();
+                //println!("{:?}", cursor.current());
+                assert_eq!(cursor.current(), Some(&i));
+            }
+            cursor.move_prev();
+            assert_eq!(cursor.current(), None);
+        }
+    }
+
+    #[test]
+    fn sanity_test() {
+        cmp_iterator(&LinkedList::from_iter(0..10), 0..10);
+        mut_cmp_iterator(&mut LinkedList::from_iter(0..10), 0..10);
+    }
+    #[test]
+    fn reverse() {
+        let list = LinkedList::from_iter(0..4);
+        let mut cursor = list.cursor();
+        for i in (0..4).rev() {
+            cursor.move_prev();
+            assert_eq!(cursor.current(), Some(&i));
+        }
+        cursor.move_prev();
+        assert_eq!(cursor.current(), None);
+    }
+    #[test]
+    fn peek() {
+        let list = LinkedList::from_iter(3..5);
+        let cursor = list.cursor();
+        assert_eq!(cursor.peek(), Some(&3));
+        assert_eq!(cursor.peek_before(), Some(&4));
+    }
+    #[test]
+    fn len() {
+        let mut list = LinkedList::from_iter(0..5);
+        assert_eq!(list.len, 5);
+        let list2 = {
+            let mut cursor = list.cursor_mut();
+            cursor.move_next();
+            cursor.move_next();
+            cursor.split()
+        };
+        assert_eq!(list.len, 2);
+        assert_eq!(list2.len, 3);
+    }
+
+    /*
+        [Node:2] <- [List] ->   [Node:0]
+            None  <- [Node:0] -> [Node:1]
+        [Node:0] -> [Node:1] -> [Node:2]
+        [Node:1] -> [Node:2] ->  None
+    
+        [Node:0] <- [List] ->   [Node:0]
+            None  <- [Node:0] ->  None
+    
+        test cases:
+            [L] cursor "points" to list:
+                current = None (= List)
+            [H] cursor has no prev:
+                current = [Node:0]
+            [T] cursor has no next:
+                current = [Node:2]
+            [G] general case:
+                current = [Node:1]
+    
+            [S] single element, neither prev nor next
+    
+    */
+
+    #[test]
+    fn split() {
+        fn test_split(n: usize, mut i: Option<usize>) {
+            let mut list = LinkedList::from_iter(0..n);
+            print!("split {:?} at {:?}", list, i);
+            let tail = {
+                let mut c = list.cursor_mut();
+                if let Some(i) = i {
+                    c.move_next();
+                    for _ in 0..i {
+                        c.move_next();
+                    }
+                }
+                println!(" = {:?}", c.current());
+                assert_eq!(i.as_mut(), c.current());
+                c.split()
+            };
+            println!("old: {:?}", list);
+            println!("new: {:?}", tail);
+            match i {
+                Some(i) => {
+                    cmp_iterator(&list, 0..=i);
+                    cmp_iterator(&tail, i + 1..n);
+                }
+                _ => {
+                    cmp_iterator(&list, 0..0);
+                    cmp_iterator(&tail, 0..n);
+                }
+            }
+        }
+
+        test_split(10, None); // case L
+        test_split(10, Some(0)); // case H
+        test_split(10, Some(9)); // case T
+        test_split(10, Some(3)); // case G
+        test_split(1, None); // case L
+        test_split(1, Some(0)); // case S
+    }
+    #[test]
+    fn split_before() {
+        fn test_split(n: usize, i: Option<usize>) {
+            let mut list = LinkedList::from_iter(0..n);
+            print!("split {:?} before {:?}", list, i);
+            let tail = {
+                let mut c = list.cursor_mut();
+                if let Some(i) = i {
+                    c.move_next();
+                    for _ in 0..i {
+                        c.move_next();
+                    }
+                }
+                println!(" = {:?}", c.current());
+                c.split_before()
+            };
+            println!("old: {:?}", list);
+            println!("new: {:?}", tail);
+            match i {
+                Some(i) => {
+                    cmp_iterator(&list, 0..i);
+                    cmp_iterator(&tail, i..n);
+                }
+                _ => {
+                    cmp_iterator(&list, 0..0);
+                    cmp_iterator(&tail, 0..n);
+                }
+            }
+        }
+
+        test_split(10, None); // case L
+        test_split(10, Some(0)); // case L
+        test_split(10, Some(1)); // case H
+        test_split(10, Some(9)); // case T
+        test_split(10, Some(3)); // case G
+        test_split(1, None); // case L
+        test_split(1, Some(0)); // case S
+    }
+    #[test]
+    fn find_pair() {
+        let list = LinkedList::from_iter(vec![5, 1, 2, 3, 3, 4, 0]);
+        assert_eq!(list.find_pair(|a, b| a > b), Some((0, &5, &1)));
+        assert_eq!(list.find_pair(|a, b| a == b), Some((3, &3, &3)));
+        assert_eq!(list.find_pair(|_, b| *b == 0), Some((5, &4, &0)));
+        assert_eq!(list.find_pair(|a, b| a + b > 100), None);
+
+        let single = LinkedList::from_iter(0..1);
+        assert_eq!(single.find_pair(|_, _| true), None);
+        let empty = LinkedList::<i32>::new();
+        assert_eq!(empty.find_pair(|_, _| true), None);
+    }
+    #[test]
+    fn fill_remaining() {
+        let mut list = LinkedList::from_iter(0..5);
+        list.cursor_mut().fill_remaining(&7);
+        cmp_iterator(&list, vec![7; 5].into_iter());
+
+        let mut list = LinkedList::from_iter(0..5);
+        {
+            let mut c = list.cursor_mut();
+            c.move_prev();
+            c.fill_remaining(&7);
+        }
+        cmp_iterator(&list, 0..5);
+
+        let mut list = LinkedList::from_iter(0..5);
+        {
+            let mut c = list.cursor_mut();
+            c.move_next();
+            c.move_next();
+            c.fill_remaining(&0);
+        }
+        cmp_iterator(&list, vec![0, 1, 0, 0, 0].into_iter());
+    }
+    #[test]
+    fn window_average() {
+        let list = LinkedList::from_iter(vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+        cmp_iterator(
+            &list.window_average(2),
+            vec![1.5, 2.5, 3.5, 4.5].into_iter(),
+        );
+        cmp_iterator(&list.window_average(3), vec![2.0, 3.0, 4.0].into_iter());
+        cmp_iterator(
+            &list.window_average(1),
+            vec![1.0, 2.0, 3.0, 4.0, 5.0].into_iter(),
+        );
+        cmp_iterator(&list.window_average(5), vec![3.0].into_iter());
+        cmp_iterator(&list.window_average(6), Vec::new().into_iter());
+
+        // the running sum should stay close to a from-scratch computation
+        let values: Vec<f64> = (0..1000).map(|i| (i as f64 * 0.37).sin() * 1e3).collect();
+        let list = LinkedList::from_iter(values.clone());
+        let averages = list.window_average(7);
+        let mut c = averages.cursor();
+        for w in values.windows(7) {
+            c.move_next();
+            let expected = w.iter().sum::<f64>() / 7.0;
+            assert!((c.current().unwrap() - expected).abs() < 1e-9);
+        }
+        c.move_next();
+        assert_eq!(c.current(), None);
+
+        // mixed magnitudes shouldn't lose the small elements
+        let list = LinkedList::from_iter(vec![1e20, 1.0, 1.0, 1.0]);
+        cmp_iterator(&list.window_average(1), vec![1e20, 1.0, 1.0, 1.0].into_iter());
+        let list = LinkedList::from_iter(vec![1.0, 1e100, 1.0, -1e100, 3.0, 5.0]);
+        cmp_iterator(
+            &list.window_average(2),
+            vec![5e99, 5e99, -5e99, -5e99, 4.0].into_iter(),
+        );
+
+        // infinities and NaNs only affect the windows containing them
+        let inf = f64::INFINITY;
+        let list = LinkedList::from_iter(vec![inf, 1.0, 2.0]);
+        cmp_iterator(&list.window_average(1), vec![inf, 1.0, 2.0].into_iter());
+        let list = LinkedList::from_iter(vec![1.0, inf, -inf, 3.0, 5.0]);
+        let averages: Vec<f64> = list.window_average(2).into_iter().collect();
+        assert_eq!(averages[0], inf);
+        assert!(averages[1].is_nan());
+        assert_eq!(averages[2], -inf);
+        assert_eq!(&averages[3..], &[4.0]);
+        let list = LinkedList::from_iter(vec![2.0, f64::NAN, 4.0, 6.0, 8.0]);
+        let averages: Vec<f64> = list.window_average(2).into_iter().collect();
+        assert!(averages[0].is_nan() && averages[1].is_nan());
+        assert_eq!(&averages[2..], &[5.0, 7.0]);
+
+        // a single infinity or NaN passing through a long list only touches
+        // the windows around it
+        for &special in &[f64::INFINITY, f64::NAN] {
+            let mut values: Vec<f64> = (0..1000).map(|i| (i as f64 * 0.37).sin() * 1e3).collect();
+            values[500] = special;
+            let averages = LinkedList::from_iter(values.clone()).window_average(7);
+            assert_eq!(averages.len(), 994);
+            for (i, (avg, w)) in averages.iter().zip(values.windows(7)).enumerate() {
+                if (494..=500).contains(&i) {
+                    assert_eq!(avg.is_nan(), special.is_nan());
+                    assert_eq!(avg.is_infinite(), special.is_infinite());
+                } else {
+                    assert!((avg - w.iter().sum::<f64>() / 7.0).abs() < 1e-9);
+                }
+            }
+        }
+
+        // the sum doesn't overflow even when the elements are huge
+        let max = f64::MAX;
+        let list = LinkedList::from_iter(vec![max, max, 1.0, 1.0]);
+        cmp_iterator(&list.window_average(2), vec![max, max / 2.0, 1.0].into_iter());
+    }
+    #[test]
+    fn rotate_segment_right() {
+        fn test_rotate(moves: usize, start: usize, len: usize, expected: Vec<usize>) {
+            let mut list = LinkedList::from_iter(0..6);
+            {
+                let mut c = list.cursor_mut();
+                for _ in 0..moves {
+                    c.move_next();
+                }
+                let before = c.current().cloned();
+                c.rotate_segment_right(start, len);
+                assert_eq!(c.current().cloned(), before);
+            }
+            cmp_iterator(&list, expected.into_iter());
+        }
+
+        test_rotate(0, 0, 3, vec![2, 0, 1, 3, 4, 5]); // start of list
+        test_rotate(0, 3, 3, vec![0, 1, 2, 5, 3, 4]); // end of list
+        test_rotate(1, 1, 3, vec![0, 1, 4, 2, 3, 5]); // middle
+        test_rotate(2, 0, 1, vec![0, 1, 2, 3, 4, 5]); // single element
+        test_rotate(2, 1, 2, vec![0, 1, 2, 4, 3, 5]); // two elements
+        test_rotate(0, 0, 6, vec![5, 0, 1, 2, 3, 4]); // whole list
+    }
+    #[test]
+    #[should_panic(expected = "segment extends past the end of the list")]
+    fn rotate_segment_right_out_of_bounds() {
+        let mut list = LinkedList::from_iter(0..6);
+        list.cursor_mut().rotate_segment_right(4, 3);
+    }
+    #[test]
+    #[should_panic(expected = "segment extends past the end of the list")]
+    fn rotate_segment_right_short_out_of_bounds() {
+        let mut list = LinkedList::from_iter(0..3);
+        {
+            // segments that are too short to rotate are still in bounds here
+            let mut c = list.cursor_mut();
+            c.rotate_segment_right(2, 1);
+            c.rotate_segment_right(3, 0);
+            c.move_next();
+            c.rotate_segment_right(1, 1);
+        }
+        cmp_iterator(&list, 0..3);
+        list.cursor_mut().rotate_segment_right(100, 1);
+    }
+    #[test]
+    fn transpose() {
+        let list = LinkedList::from_iter((0..4).map(|i| Some(Some(i))));
+        cmp_iterator(&list.transpose(), 0..4);
+
+        let list = LinkedList::from_iter(vec![None, Some(None), None]);
+        cmp_iterator(&list.transpose(), 0..0);
+
+        let list = LinkedList::from_iter(vec![
+            Some(Some(0)),
+            None,
+            Some(None),
+            Some(Some(1)),
+            Some(Some(2)),
+            None,
+        ]);
+        let flat = list.transpose();
+        assert_eq!(flat.len, 3);
+        cmp_iterator(&flat, 0..3);
+    }
+    #[test]
+    fn step_over_if() {
+        let mut list = LinkedList::from_iter(0..4);
+        let mut c = list.cursor_mut();
+        assert!(!c.step_over_if(|_| true));
+        assert_eq!(c.current(), None);
+
+        c.move_next();
+        assert!(c.step_over_if(|x| *x == 0));
+        assert_eq!(c.current(), Some(&mut 1));
+        assert!(!c.step_over_if(|x| *x == 0));
+        assert_eq!(c.current(), Some(&mut 1));
+        while c.step_over_if(|x| *x < 10) {}
+        assert_eq!(c.current(), None);
+    }
+    #[test]
+    fn from_repeating_iter() {
+        let list = LinkedList::from_repeating_iter(0..2, 0);
+        cmp_iterator(&list, 0..0);
+        let list = LinkedList::from_repeating_iter(0..2, 1);
+        cmp_iterator(&list, 0..2);
+        let list = LinkedList::from_repeating_iter(vec![1, 2], 3);
+        assert_eq!(list.len, 6);
+        cmp_iterator(&list, vec![1, 2, 1, 2, 1, 2].into_iter());
+
+        // each pass starts from a fresh clone of the iterator
+        let mut source = 0..3;
+        source.next();
+        let list = LinkedList::from_repeating_iter(source.clone(), 2);
+        cmp_iterator(&list, vec![1, 2, 1, 2].into_iter());
+        assert_eq!(source.next(), Some(1));
+    }
+    #[test]
+    fn all_any_from_here() {
+        let list = LinkedList::from_iter(vec![3, 1, 2, 4]);
+        let mut c = list.cursor();
+        assert!(c.all_from_here(|_| false));
+        assert!(!c.any_from_here(|_| true));
+
+        c.move_next();
+        assert!(c.all_from_here(|x| *x > 0));
+        assert!(!c.all_from_here(|x| *x > 1));
+        assert!(c.any_from_here(|x| *x == 4));
+        assert!(!c.any_from_here(|x| *x > 4));
+
+        c.move_next();
+        assert!(c.all_from_here(|x| *x < 5));
+        assert!(!c.any_from_here(|x| *x == 3));
+
+        // both stop at the first deciding element
+        let mut calls = 0;
+        assert!(!c.all_from_here(|x| {
+            calls += 1;
+            *x != 2
+        }));
+        assert_eq!(calls, 2);
+        calls = 0;
+        assert!(c.any_from_here(|x| {
+            calls += 1;
+            *x == 1
+        }));
+        assert_eq!(calls, 1);
+        assert_eq!(c.current(), Some(&1));
+
+        let empty = LinkedList::<i32>::new();
+        let c = empty.cursor();
+        assert!(c.all_from_here(|_| false));
+        assert!(!c.any_from_here(|_| true));
+    }
+    #[test]
+    fn merge_adjacent_pairs() {
+        let list = LinkedList::from_iter(0..6);
+        cmp_iterator(
+            &list.merge_adjacent_pairs(|a, b| a + b),
+            vec![1, 5, 9].into_iter(),
+        );
+
+        let list = LinkedList::from_iter(0..5);
+        cmp_iterator(
+            &list.merge_adjacent_pairs(|a, b| a + b),
+            vec![1, 5, 4].into_iter(),
+        );
+
+        let list = LinkedList::from_iter(0..1);
+        cmp_iterator(&list.merge_adjacent_pairs(|a, b| a + b), 0..1);
+
+        let list = LinkedList::<i32>::new();
+        cmp_iterator(&list.merge_adjacent_pairs(|a, b| a + b), 0..0);
+
+        // pairs don't overlap and keep their order
+        let list = LinkedList::from_iter(vec!["a", "b", "c", "d"].into_iter().map(String::from));
+        let merged = list.merge_adjacent_pairs(|a, b| a + &b);
+        cmp_iterator(
+            &merged,
+            vec!["ab".to_string(), "cd".to_string()].into_iter(),
+        );
+    }
+    #[test]
+    fn normalize_current_len() {
+        let mut list = LinkedList::from_iter(0..5);
+        let mut c = list.cursor_mut();
+        c.current_len = 3;
+        c.normalize_current_len();
+        assert_eq!(c.current_len, 0);
+
+        for i in 1..6 {
+            c.move_next();
+            c.current_len = 42;
+            c.normalize_current_len();
+            assert_eq!(c.current_len, i);
+        }
+    }
+    #[test]
+    fn into_indexed_list() {
+        let list = LinkedList::from_iter(vec!['a', 'b', 'c']);
+        cmp_iterator(
+            &list.into_indexed_list(),
+            vec![(0, 'a'), (1, 'b'), (2, 'c')].into_iter(),
+        );
+        let list = LinkedList::<char>::new();
+        cmp_iterator(&list.into_indexed_list(), Vec::new().into_iter());
+    }
+    #[test]
+    fn invert_all_remaining() {
+        let mut list = LinkedList::from_iter(1..5);
+        list.cursor_mut().invert_all_remaining();
+        cmp_iterator(&list, vec![-1, -2, -3, -4].into_iter());
+
+        let mut list = LinkedList::from_iter(1..5);
+        {
+            let mut c = list.cursor_mut();
+            c.move_next();
+            c.move_next();
+            c.invert_all_remaining();
+        }
+        cmp_iterator(&list, vec![1, -2, -3, -4].into_iter());
+
+        let mut list = LinkedList::from_iter(1..5);
+        {
+            let mut c = list.cursor_mut();
+            c.move_prev();
+            c.invert_all_remaining();
+        }
+        cmp_iterator(&list, vec![1, 2, 3, -4].into_iter());
+
+        use std::ops::Neg;
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        struct Sign(bool);
+        impl Neg for Sign {
+            type Output = Sign;
+            fn neg(self) -> Sign {
+                Sign(!self.0)
+            }
+        }
+        let mut list = LinkedList::from_iter(vec![Sign(true), Sign(false)]);
+        list.cursor_mut().invert_all_remaining();
+        cmp_iterator(&list, vec![Sign(false), Sign(true)].into_iter());
+    }
+    #[test]
+    fn drain_while() {
+        let mut list = LinkedList::from_iter(0..5);
+        let drained = list.drain_while(|_| true);
+        cmp_iterator(&drained, 0..5);
+        cmp_iterator(&list, 0..0);
+
+        let mut list = LinkedList::from_iter(0..5);
+        let drained = list.drain_while(|_| false);
+        cmp_iterator(&drained, 0..0);
+        cmp_iterator(&list, 0..5);
+
+        let mut list = LinkedList::from_iter(vec![0, 1, 2, 3, 0, 1]);
+        let drained = list.drain_while(|x| *x < 3);
+        assert_eq!((drained.len, list.len), (3, 3));
+        cmp_iterator(&drained, 0..3);
+        cmp_iterator(&list, vec![3, 0, 1].into_iter());
+
+        let mut list = LinkedList::<i32>::new();
+        cmp_iterator(&list.drain_while(|_| true), 0..0);
+        cmp_iterator(&list, 0..0);
+    }
+    #[test]
+    fn to_graphviz() {
+        let list = LinkedList::from_iter(vec!["a", "say \"hi\"", "C:\\dir"]);
+        assert_eq!(
+            list.to_graphviz(),
+            concat!(
+                "digraph LinkedList {\n",
+                "    node [shape=box];\n",
+                "    n0 [label=\"a\"];\n",
+                "    n1 [label=\"say \\\"hi\\\"\"];\n",
+                "    n0 -> n1;\n",
+                "    n1 -> n0 [style=dashed];\n",
+                "    n2 [label=\"C:\\\\dir\"];\n",
+                "    n1 -> n2;\n",
+                "    n2 -> n1 [style=dashed];\n",
+                "}\n",
+            )
+        );
+
+        let list = LinkedList::<i32>::new();
+        assert_eq!(
+            list.to_graphviz(),
+            "digraph LinkedList {\n    node [shape=box];\n}\n"
+        );
+    }
+    #[test]
+    fn ensure_sorted_before_insert() {
+        let mut list = LinkedList::from_iter(vec![1, 3, 3, 5]);
+        {
+            let mut c = list.cursor_mut();
+            // before the head
+            assert!(c.ensure_sorted_before_insert(&0));
+            assert!(c.ensure_sorted_before_insert(&1));
+            assert!(!c.ensure_sorted_before_insert(&2));
+
+            c.move_next();
+            assert!(c.ensure_sorted_before_insert(&2));
+            assert!(c.ensure_sorted_before_insert(&3));
+            assert!(!c.ensure_sorted_before_insert(&0));
+            assert!(!c.ensure_sorted_before_insert(&4));
+
+            // between equal elements
+            c.move_next();
+            assert!(c.ensure_sorted_before_insert(&3));
+            assert!(!c.ensure_sorted_before_insert(&4));
+
+            // after the tail
+            c.move_next();
+            c.move_next();
+            assert!(c.ensure_sorted_before_insert(&5));
+            assert!(c.ensure_sorted_before_insert(&9));
+            assert!(!c.ensure_sorted_before_insert(&4));
+            if c.ensure_sorted_before_insert(&7) {
+                c.insert(7);
+            }
+        }
+        cmp_iterator(&list, vec![1, 3, 3, 5, 7].into_iter());
+
+        let mut empty = LinkedList::new();
+        assert!(empty.cursor_mut().ensure_sorted_before_insert(&0));
+    }
+    #[test]
+    fn flatten_results() {
+        let list = LinkedList::from_iter((0..3).map(Ok::<i32, char>));
+        let (oks, errs) = list.flatten_results();
+        cmp_iterator(&oks, 0..3);
+        cmp_iterator(&errs, Vec::new().into_iter());
+
+        let list = LinkedList::from_iter(vec![Err::<i32, char>('a'), Err('b')]);
+        let (oks, errs) = list.flatten_results();
+        cmp_iterator(&oks, 0..0);
+        cmp_iterator(&errs, vec!['a', 'b'].into_iter());
+
+        let list = LinkedList::from_iter(vec![Ok(0), Err('a'), Ok(1), Ok(2), Err('b')]);
+        let (oks, errs) = list.flatten_results();
+        cmp_iterator(&oks, 0..3);
+        cmp_iterator(&errs, vec!['a', 'b'].into_iter());
+
+        let list = LinkedList::<Result<i32, char>>::new();
+        let (oks, errs) = list.flatten_results();
+        assert_eq!((oks.len, errs.len), (0, 0));
+    }
+    #[test]
+    fn nth_back() {
+        let list = LinkedList::from_iter(0..5);
+        let mut c = list.cursor();
+        assert_eq!(c.nth_back(0), Some(&4));
+        assert_eq!(c.nth_back(4), Some(&0));
+        assert_eq!(c.nth_back(5), None);
+
+        c.move_next();
+        c.move_next();
+        c.move_next();
+        assert_eq!(c.nth_back(0), c.peek_before());
+        assert_eq!(c.nth_back(1), Some(&0));
+        assert_eq!(c.nth_back(2), None);
+        assert_eq!(c.nth_back(100), None);
+    }
+    #[test]
+    fn absorb_cursor() {
+        let mut list = LinkedList::from_iter(vec![0, 1, 5]);
+        let mut other = LinkedList::from_iter(2..5);
+        {
+            let mut c = list.cursor_mut();
+            c.move_next();
+            c.move_next();
+            assert_eq!(c.absorb_cursor(other.cursor_mut()), 3);
+            assert_eq!(c.current(), Some(&mut 1));
+            assert_eq!(c.peek(), Some(&mut 2));
+        }
+        assert_eq!(list.len, 6);
+        cmp_iterator(&list, 0..6);
+        assert_eq!(other.len, 0);
+        cmp_iterator(&other, 0..0);
+
+        // absorbing at either end and absorbing an empty list
+        let mut list = LinkedList::new();
+        let mut other = LinkedList::from_iter(0..2);
+        assert_eq!(list.cursor_mut().absorb_cursor(other.cursor_mut()), 2);
+        assert_eq!(list.cursor_mut().absorb_cursor(other.cursor_mut()), 0);
+        let mut other = LinkedList::from_iter(2..4);
+        {
+            let mut c = list.cursor_mut();
+            c.move_prev();
+            c.absorb_cursor(other.cursor_mut());
+        }
+        cmp_iterator(&list, 0..4);
+    }
+    #[test]
+    fn build() {
+        let list = LinkedList::build(|_: &mut CursorMut<i32>| {});
+        cmp_iterator(&list, 0..0);
+
+        let list = LinkedList::build(|c| {
+            c.insert(2);
+            c.insert(1);
+        });
+        cmp_iterator(&list, 1..3);
+
+        let list = LinkedList::build(|c| {
+            for i in 0..100 {
+                c.insert_before(i);
+            }
+            c.pop();
+        });
+        assert_eq!(list.len, 99);
+        cmp_iterator(&list, 1..100);
+    }
+    #[test]
+    fn extend_from_cursor() {
+        let mut list = LinkedList::from_iter(vec![0, 9]);
+        let mut source = LinkedList::from_iter(1..6);
+        {
+            let mut c = list.cursor_mut();
+            c.move_next();
+            let mut src = source.cursor_mut();
+            assert_eq!(c.extend_from_cursor(&mut src, 3), 3);
+            assert_eq!(src.current(), Some(&mut 3));
+            assert_eq!(c.current(), Some(&mut 0));
+
+            // only two elements remain in `src`
+            c.move_prev();
+            assert_eq!(c.extend_from_cursor(&mut src, 3), 2);
+            assert_eq!(src.current(), Some(&mut 5));
+            assert_eq!(c.extend_from_cursor(&mut src, 3), 0);
+        }
+        assert_eq!(list.len, 7);
+        cmp_iterator(&list, vec![4, 5, 0, 1, 2, 3, 9].into_iter());
+        cmp_iterator(&source, 1..6);
+    }
+    #[test]
+    fn into_iter_chunks() {
+        let mut chunks = LinkedList::from_iter(0..8).into_iter_chunks(3);
+        // nothing is split off until the iterator is advanced
+        assert_eq!(chunks.list.len, 8);
+        cmp_iterator(&chunks.next().unwrap(), 0..3);
+        assert_eq!(chunks.list.len, 5);
+        cmp_iterator(&chunks.next().unwrap(), 3..6);
+        cmp_iterator(&chunks.next().unwrap(), 6..8);
+        assert!(chunks.next().is_none());
+
+        let mut chunks = LinkedList::from_iter(0..2).into_iter_chunks(5);
+        cmp_iterator(&chunks.next().unwrap(), 0..2);
+        assert!(chunks.next().is_none());
+        assert!(LinkedList::<i32>::new()
+            .into_iter_chunks(1)
+            .next()
+            .is_none());
+
+        // elements left in a partially consumed iterator are still dropped
+        let drops = Cell::new(0);
+        {
+            let mut chunks =
+                LinkedList::from_iter((0..7).map(|_| DropCounter(&drops))).into_iter_chunks(2);
+            chunks.next();
+            assert_eq!(drops.get(), 2);
+        }
+        assert_eq!(drops.get(), 7);
+    }
+    #[test]
+    fn advance_then_pop() {
+        let mut list = LinkedList::from_iter(0..4);
+        {
+            let mut c = list.cursor_mut();
+            c.move_next();
+            let peeked = c.peek().cloned();
+            assert_eq!(c.advance_then_pop(), peeked);
+            assert_eq!(c.current(), Some(&mut 2));
+            assert_eq!(c.current_len, 2);
+            assert_eq!(c.advance_then_pop(), Some(3));
+            assert_eq!(c.current(), None);
+            assert_eq!(c.advance_then_pop(), Some(0));
+            assert_eq!(c.current(), Some(&mut 2));
+            assert_eq!(c.advance_then_pop(), None);
+            assert_eq!(c.current(), Some(&mut 2));
+        }
+        cmp_iterator(&list, 2..3);
+    }
+    #[test]
+    fn pop_n() {
+        let mut list = LinkedList::from_iter(0..6);
+        {
+            let mut c = list.cursor_mut();
+            c.move_next();
+            assert_eq!(c.pop_n(0), Vec::<i32>::new());
+            assert_eq!(c.pop_n(2), vec![1, 2]);
+            assert_eq!(c.list.len, 4);
+            assert_eq!(c.pop_n(5), vec![3, 4, 5]);
+            assert_eq!(c.list.len, 1);
+            assert_eq!(c.pop_n(1), Vec::<i32>::new());
+        }
+        cmp_iterator(&list, 0..1);
+    }
+    #[test]
+    fn into_iter_filter_map() {
+        let calls = Cell::new(0);
+        let mapped: Vec<_> = LinkedList::from_iter(0..5)
+            .into_iter_filter_map(|x| {
+                calls.set(calls.get() + 1);
+                Some(x * 2)
+            })
+            .collect();
+        assert_eq!(mapped, vec![0, 2, 4, 6, 8]);
+        assert_eq!(calls.get(), 5);
+
+        let mapped: Vec<i32> = LinkedList::from_iter(0..5)
+            .into_iter_filter_map(|_| None)
+            .collect();
+        assert!(mapped.is_empty());
+
+        let mapped: Vec<_> = LinkedList::from_iter(0..10)
+            .into_iter_filter_map(|x| if x % 3 == 0 { Some(x / 3) } else { None })
+            .collect();
+        assert_eq!(mapped, vec![0, 1, 2, 3]);
+
+        // the remaining nodes are freed when the iterator is dropped early
+        let drops = Cell::new(0);
+        {
+            let mut iter = LinkedList::from_iter((0..5).map(|_| DropCounter(&drops)))
+                .into_iter_filter_map(Some);
+            let first = iter.next();
+            assert!(first.is_some());
+            assert_eq!(drops.get(), 0);
+        }
+        assert_eq!(drops.get(), 5);
+    }
+    #[test]
+    fn zip3() {
+        let a = LinkedList::from_iter(0..3);
+        let b = LinkedList::from_iter(vec!['a', 'b', 'c', 'd']);
+        let c = LinkedList::from_iter(vec!["x", "y", "z"]);
+        let (mut ca, mut cb, mut cc) = (a.cursor(), b.cursor(), c.cursor());
+
+        assert_eq!(
+            Cursor::zip3(&mut ca, &mut cb, &mut cc),
+            Some((&0, &'a', &"x"))
+        );
+        assert_eq!(
+            Cursor::zip3(&mut ca, &mut cb, &mut cc),
+            Some((&1, &'b', &"y"))
+        );
+        assert_eq!(
+            Cursor::zip3(&mut ca, &mut cb, &mut cc),
+            Some((&2, &'c', &"z"))
+        );
+        assert_eq!(Cursor::zip3(&mut ca, &mut cb, &mut cc), None);
+        // no cursor moves when one of them has run out
+        assert_eq!(ca.current(), Some(&2));
+        assert_eq!(cb.current(), Some(&'c'));
+        assert_eq!(cc.current(), Some(&"z"));
+    }
+    #[test]
+    fn into_min_max_pair() {
+        assert_eq!(LinkedList::<i32>::new().into_min_max_pair(), None);
+        assert_eq!(
+            LinkedList::from_iter(vec![7]).into_min_max_pair(),
+            Some((7, 7))
+        );
+        assert_eq!(
+            LinkedList::from_iter(vec![2; 4]).into_min_max_pair(),
+            Some((2, 2))
+        );
+        assert_eq!(
+            LinkedList::from_iter(0..10).into_min_max_pair(),
+            Some((0, 9))
+        );
+        assert_eq!(
+            LinkedList::from_iter((0..10).rev()).into_min_max_pair(),
+            Some((0, 9))
+        );
+
+        // a simple LCG stands in for random input
+        let mut state = 12345u64;
+        let values: Vec<u64> = (0..200)
+            .map(|_| {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+                state >> 33
+            })
+            .collect();
+        let expected = (*values.iter().min().unwrap(), *values.iter().max().unwrap());
+        assert_eq!(
+            LinkedList::from_iter(values).into_min_max_pair(),
+            Some(expected)
+        );
+    }
+    #[test]
+    fn rotate_current_backward() {
+        fn test_rotate(at: usize, n: usize, expected: Vec<usize>, expected_len: usize) {
+            let mut list = LinkedList::from_iter(0..5);
+            {
+                let mut c = list.cursor_mut();
+                for _ in 0..=at {
+                    c.move_next();
+                }
+                c.rotate_current_backward(n);
+                assert_eq!(c.current().cloned(), Some(at));
+                assert_eq!(c.current_len, expected_len);
+            }
+            cmp_iterator(&list, expected.into_iter());
+        }
+
+        test_rotate(3, 0, vec![0, 1, 2, 3, 4], 4);
+        test_rotate(3, 1, vec![0, 1, 3, 2, 4], 3);
+        test_rotate(4, 4, vec![4, 0, 1, 2, 3], 1);
+        test_rotate(2, 10, vec![2, 0, 1, 3, 4], 1);
+        test_rotate(0, 1, vec![0, 1, 2, 3, 4], 1);
+
+        // nothing to move at the empty element
+        let mut list = LinkedList::from_iter(0..3);
+        list.cursor_mut().rotate_current_backward(1);
+        cmp_iterator(&list, 0..3);
+    }
+    #[test]
+    fn from_ordered_pairs() {
+        let list = LinkedList::from_ordered_pairs(vec![(3, 'a'), (1, 'b'), (2, 'c'), (1, 'd')]);
+        cmp_iterator(
+            &list,
+            vec![(1, 'b'), (1, 'd'), (2, 'c'), (3, 'a')].into_iter(),
+        );
+        let list = LinkedList::from_ordered_pairs(Vec::<(i32, char)>::new());
+        cmp_iterator(&list, Vec::new().into_iter());
+    }
+    #[test]
+    fn collect_and_clear() {
+        let mut list = LinkedList::from_iter(0..5);
+        {
+            let mut c = list.cursor_mut();
+            c.move_next();
+            c.move_next();
+            assert_eq!(c.collect_and_clear(), vec![0, 1, 2, 3, 4]);
+            assert_eq!(c.current(), None);
+            assert_eq!(c.current_len, 0);
+            assert_eq!(c.collect_and_clear(), Vec::<i32>::new());
+        }
+        assert_eq!(list.len, 0);
+        cmp_iterator(&list, 0..0);
+    }
+    #[test]
+    fn into_two_halves() {
+        for n in 0usize..8 {
+            let (first, second) = LinkedList::from_iter(0..n).into_two_halves();
+            let mid = n.div_ceil(2);
+            assert_eq!((first.len, second.len), (mid, n - mid));
+            cmp_iterator(&first, 0..mid);
+            cmp_iterator(&second, mid..n);
+        }
+    }
+    #[test]
+    fn split_while() {
+        let mut list = LinkedList::from_iter(vec![0, 1, 2, 3, 10, 4]);
+        let run = {
+            let mut c = list.cursor_mut();
+            c.move_next();
+            let run = c.split_while(|x| *x < 5);
+            assert_eq!(c.current(), Some(&mut 0));
+            assert_eq!(c.peek(), Some(&mut 10));
+            assert_eq!(c.current_len, 1);
+            run
+        };
+        assert_eq!((list.len, run.len), (3, 3));
+        cmp_iterator(&run, 1..4);
+        cmp_iterator(&list, vec![0, 10, 4].into_iter());
+
+        // from the empty element, taking the whole list
+        let mut list = LinkedList::from_iter(0..4);
+        let run = list.cursor_mut().split_while(|_| true);
+        cmp_iterator(&run, 0..4);
+        cmp_iterator(&list, 0..0);
+
+        // nothing matches
+        let mut list = LinkedList::from_iter(0..4);
+        let run = list.cursor_mut().split_while(|_| false);
+        cmp_iterator(&run, 0..0);
+        cmp_iterator(&list, 0..4);
+
+        // a run ending at the tail
+        let mut list = LinkedList::from_iter(0..4);
+        let run = {
+            let mut c = list.cursor_mut();
+            c.move_next();
+            c.move_next();
+            c.split_while(|_| true)
+        };
+        cmp_iterator(&run, 2..4);
+        cmp_iterator(&list, 0..2);
+    }
+    #[test]
+    fn nwise() {
+        let pairs: Vec<[i32; 2]> = LinkedList::from_iter(0..7).nwise().collect();
+        assert_eq!(pairs, vec![[0, 1], [2, 3], [4, 5]]);
+        let singles: Vec<[i32; 1]> = LinkedList::from_iter(0..3).nwise().collect();
+        assert_eq!(singles, vec![[0], [1], [2]]);
+        let triples: Vec<[i32; 3]> = LinkedList::from_iter(0..6).nwise().collect();
+        assert_eq!(triples, vec![[0, 1, 2], [3, 4, 5]]);
+        assert_eq!(LinkedList::from_iter(0..3).nwise::<4>().next(), None);
+
+        // the leftover elements are dropped along with the iterator
+        let drops = Cell::new(0);
+        {
+            let mut groups =
+                LinkedList::from_iter((0..5).map(|_| DropCounter(&drops))).nwise::<2>();
+            drop(groups.next());
+            drop(groups.next());
+            assert_eq!(drops.get(), 4);
+            assert!(groups.next().is_none());
+        }
+        assert_eq!(drops.get(), 5);
+    }
+    #[test]
+    fn is_empty() {
+        let mut list = LinkedList::new();
+        assert!(list.is_empty());
+        assert_eq!(list.len(), 0);
+        {
+            let mut c = list.cursor_mut();
+            c.insert(1);
+            c.insert(0);
+        }
+        assert!(!list.is_empty());
+        assert_eq!(list.len(), 2);
+        {
+            let mut c = list.cursor_mut();
+            while c.pop().is_some() {}
+        }
+        assert!(list.is_empty());
+        assert_eq!(list.len(), 0);
+    }
+    #[test]
+    fn iter() {
+        let empty = LinkedList::<i32>::new();
+        assert_eq!(empty.iter().next(), None);
+        assert_eq!(empty.iter().next_back(), None);
+
+        let single = LinkedList::from_iter(0..1);
+        let mut it = single.iter();
+        assert_eq!(it.len(), 1);
+        assert_eq!(it.next_back(), Some(&0));
+        assert_eq!(it.next(), None);
+        assert_eq!(it.next_back(), None);
+
+        let list = LinkedList::from_iter(0..10);
+        assert!(list.iter().eq((0..10).collect::<Vec<_>>().iter()));
+        assert!(list
+            .iter()
+            .rev()
+            .eq((0..10).rev().collect::<Vec<_>>().iter()));
+
+        // alternating ends meet in the middle without overlapping
+        let mut it = list.iter();
+        let mut seen = Vec::new();
+        while let Some(front) = it.next() {
+            seen.push(*front);
+            assert_eq!(it.len(), 10 - seen.len());
+            if let Some(back) = it.next_back() {
+                seen.push(*back);
+            }
+        }
+        assert_eq!(seen, vec![0, 9, 1, 8, 2, 7, 3, 6, 4, 5]);
+        assert_eq!(it.next(), None);
+        assert_eq!(it.next_back(), None);
+
+        let mut it = list.iter();
+        it.next();
+        let copy = it.clone();
+        assert!(it.eq(copy));
+    }
+    #[test]
+    fn iter_mut() {
+        let mut empty = LinkedList::<i32>::new();
+        assert_eq!(empty.iter_mut().next(), None);
+
+        let mut list = LinkedList::from_iter(0..5);
+        for x in list.iter_mut() {
+            *x *= 10;
+        }
+        cmp_iterator(&list, (0..5).map(|x| x * 10));
+
+        {
+            let mut it = list.iter_mut().rev();
+            assert_eq!(it.len(), 5);
+            *it.next().unwrap() = -1;
+            assert_eq!(it.next(), Some(&mut 30));
+        }
+        cmp_iterator(&list, vec![0, 10, 20, 30, -1].into_iter());
+
+        let mut it = list.iter_mut();
+        assert_eq!(it.next(), Some(&mut 0));
+        assert_eq!(it.next_back(), Some(&mut -1));
+        assert_eq!(it.len(), 3);
+        assert_eq!(it.next(), Some(&mut 10));
+        assert_eq!(it.next_back(), Some(&mut 30));
+        assert_eq!(it.next(), Some(&mut 20));
+        assert_eq!(it.next_back(), None);
+        assert_eq!(it.next(), None);
+    }
+    #[test]
+    fn into_iter() {
+        let mut expected = 0;
+        for x in LinkedList::from_iter(0..5) {
+            assert_eq!(x, expected);
+            expected += 1;
+        }
+        assert_eq!(expected, 5);
+
+        let mut it = LinkedList::from_iter(0..5).into_iter();
+        assert_eq!(it.len(), 5);
+        assert_eq!(it.next_back(), Some(4));
+        assert_eq!(it.next(), Some(0));
+        assert_eq!(it.len(), 3);
+        assert_eq!(it.collect::<Vec<_>>(), vec![1, 2, 3]);
+
+        let drops = Cell::new(0);
+        {
+            let mut it = LinkedList::from_iter((0..4).map(|_| DropCounter(&drops))).into_iter();
+            drop(it.next());
+            assert_eq!(drops.get(), 1);
+        }
+        assert_eq!(drops.get(), 4);
+
+        let mut list = LinkedList::from_iter(0..3);
+        for x in &mut list {
+            *x += 1;
+        }
+        let mut sum = 0;
+        for x in &list {
+            sum += *x;
+        }
+        assert_eq!(sum, 6);
+    }
+    #[test]
+    fn clone() {
+        let list = LinkedList::from_iter(0..5);
+        let mut copy = list.clone();
+        assert_eq!((list.len, copy.len), (5, 5));
+        cmp_iterator(&copy, 0..5);
+        {
+            let mut c = copy.cursor_mut();
+            c.move_next();
+            *c.current().unwrap() = 10;
+            c.pop();
+            c.insert_before(-1);
+        }
+        assert_eq!((list.len, copy.len), (5, 5));
+        cmp_iterator(&list, 0..5);
+        cmp_iterator(&copy, vec![-1, 10, 2, 3, 4].into_iter());
+        drop(list);
+        cmp_iterator(&copy, vec![-1, 10, 2, 3, 4].into_iter());
+
+        let empty = LinkedList::<i32>::new().clone();
+        cmp_iterator(&empty, 0..0);
+    }
+    #[test]
+    fn eq() {
+        assert_eq!(LinkedList::<i32>::new(), LinkedList::new());
+
+        let list = LinkedList::from_iter(0..4);
+        assert_eq!(list, list);
+        assert_eq!(list, LinkedList::from_iter(0..4));
+        assert_ne!(list, LinkedList::from_iter(0..3));
+        assert_ne!(list, LinkedList::from_iter(0..5));
+        assert_ne!(list, LinkedList::from_iter(vec![0, 2, 1, 3]));
+        assert_ne!(list, LinkedList::new());
+
+        let mut manual = LinkedList::new();
+        {
+            let mut c = manual.cursor_mut();
+            c.insert(3);
+            c.insert(0);
+            c.move_next();
+            c.insert(2);
+            c.insert(1);
+        }
+        assert_eq!(manual, list);
+    }
+    #[test]
+    fn hash() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::collections::HashSet;
+        use std::hash::{Hash, Hasher};
+
+        fn hash_of<T: Hash>(t: &T) -> u64 {
+            let mut s = DefaultHasher::new();
+            t.hash(&mut s);
+            s.finish()
+        }
+
+        let list = LinkedList::from_iter(0..5);
+        assert_eq!(hash_of(&list), hash_of(&LinkedList::from_iter(0..5)));
+        assert_ne!(
+            hash_of(&list),
+            hash_of(&LinkedList::from_iter(vec![0, 1, 2, 3, 5]))
+        );
+        assert_ne!(hash_of(&list), hash_of(&LinkedList::from_iter(0..4)));
+
+        // lists of lists hash differently depending on where the splits are
+        let a = LinkedList::from_iter(vec![
+            LinkedList::from_iter(0..1),
+            LinkedList::from_iter(1..3),
+        ]);
+        let b = LinkedList::from_iter(vec![
+            LinkedList::from_iter(0..2),
+            LinkedList::from_iter(2..3),
+        ]);
+        assert_ne!(hash_of(&a), hash_of(&b));
+
+        let mut set = HashSet::new();
+        assert!(set.insert(list));
+        assert!(!set.insert(LinkedList::from_iter(0..5)));
+        assert!(set.contains(&LinkedList::from_iter(0..5)));
+    }
+    #[test]
+    fn ord() {
+        use std::cmp::Ordering;
+        use std::collections::BTreeSet;
+
+        let list = |v: Vec<i32>| LinkedList::from_iter(v);
+        assert!(list(vec![1, 2, 3]) < list(vec![1, 2, 4]));
+        assert!(list(vec![1, 2]) < list(vec![1, 2, 3]));
+        assert!(list(vec![]) < list(vec![0]));
+        assert!(list(vec![5]) > list(vec![4]));
+        assert_eq!(
+            list(vec![1, 2, 3]).cmp(&list(vec![1, 2, 3])),
+            Ordering::Equal
+        );
+        assert_eq!(
+            LinkedList::from_iter(vec![1.0, f64::NAN])
+                .partial_cmp(&LinkedList::from_iter(vec![1.0, 2.0])),
+            None
+        );
+
+        let mut set = BTreeSet::new();
+        set.insert(list(vec![2]));
+        set.insert(list(vec![1, 5]));
+        set.insert(list(vec![]));
+        set.insert(list(vec![1, 5]));
+        let ordered: Vec<_> = set.into_iter().collect();
+        assert_eq!(ordered, vec![list(vec![]), list(vec![1, 5]), list(vec![2])]);
+    }
+    #[test]
+    fn push_pop() {
+        // as a stack
+        let mut list = LinkedList::new();
+        for i in 0..4 {
+            list.push_back(i);
+        }
+        assert_eq!(list.len(), 4);
+        cmp_iterator(&list, 0..4);
+        for i in (0..4).rev() {
+            assert_eq!(list.pop_back(), Some(i));
+            cmp_iterator(&list, 0..i);
+        }
+        assert_eq!(list.pop_back(), None);
+        assert!(list.is_empty());
+
+        // as a queue
+        for i in 0..4 {
+            list.push_front(i);
+        }
+        cmp_iterator(&list, (0..4).rev());
+        for i in 0..4 {
+            assert_eq!(list.pop_back(), Some(i));
+        }
+        assert_eq!(list.pop_front(), None);
+
+        list.push_back(1);
+        list.push_front(0);
+        list.push_back(2);
+        assert_eq!(list.pop_front(), Some(0));
+        cmp_iterator(&list, 1..3);
+        assert_eq!(list.len(), 2);
+    }
+    #[test]
+    fn front_back() {
+        let mut list = LinkedList::new();
+        assert_eq!(list.front(), None);
+        assert_eq!(list.back(), None);
+        assert_eq!(list.front_mut(), None);
+        assert_eq!(list.back_mut(), None);
+
+        list.push_back(1);
+        assert_eq!(list.front(), Some(&1));
+        assert_eq!(list.front(), list.back());
+
+        list.push_back(2);
+        *list.front_mut().unwrap() = 10;
+        *list.back_mut().unwrap() += 10;
+        assert_eq!(list.front(), Some(&10));
+        assert_eq!(list.back(), Some(&12));
+
+        list.cursor_mut().insert(0);
+        assert_eq!(list.front(), Some(&0));
+        cmp_iterator(&list, vec![0, 10, 12].into_iter());
+    }
+    #[test]
+    fn cursor_front_back() {
+        let mut list = LinkedList::from_iter(0..4);
+        assert_eq!(list.cursor_front().current(), list.front());
+        assert_eq!(list.cursor_back().current(), list.back());
+
+        let mut c = list.cursor_front();
+        c.move_prev();
+        assert_eq!(c.current(), None);
+        let mut c = list.cursor_back();
+        c.move_next();
+        assert_eq!(c.current(), None);
+
+        {
+            let mut c = list.cursor_front_mut();
+            assert_eq!(c.current(), Some(&mut 0));
+            assert_eq!(c.current_len, 1);
+            c.move_prev();
+            assert_eq!(c.current(), None);
+            assert_eq!(c.current_len, 0);
+        }
+        {
+            let mut c = list.cursor_back_mut();
+            assert_eq!(c.current(), Some(&mut 3));
+            assert_eq!(c.current_len, 4);
+            c.move_next();
+            assert_eq!(c.current_len, 0);
+        }
+
+        let mut empty = LinkedList::<i32>::new();
+        assert_eq!(empty.cursor_front().current(), None);
+        assert_eq!(empty.cursor_back().current(), None);
+        assert_eq!(empty.cursor_front_mut().current_len, 0);
+        assert_eq!(empty.cursor_back_mut().current(), None);
+    }
+    #[test]
+    fn remove_current() {
+        let mut list = LinkedList::from_iter(0..5);
+        {
+            let mut c = list.cursor_mut();
+            assert_eq!(c.remove_current(), None);
+
+            // head
+            c.move_next();
+            assert_eq!(c.remove_current(), Some(0));
+            assert_eq!((c.current().cloned(), c.current_len), (Some(1), 1));
+            // middle
+            c.move_next();
+            assert_eq!(c.remove_current(), Some(2));
+            assert_eq!((c.current().cloned(), c.current_len), (Some(3), 2));
+            // tail
+            c.move_next();
+            assert_eq!(c.remove_current(), Some(4));
+            assert_eq!((c.current().cloned(), c.current_len), (None, 0));
+        }
+        assert_eq!(list.len(), 2);
+        cmp_iterator(&list, vec![1, 3].into_iter());
+
+        let mut list = LinkedList::from_iter(0..1);
+        {
+            let mut c = list.cursor_front_mut();
+            assert_eq!(c.remove_current(), Some(0));
+            assert_eq!(c.current(), None);
+        }
+        assert!(list.is_empty());
+        cmp_iterator(&list, 0..0);
+    }
+    #[test]
+    fn replace_current() {
+        let mut list = LinkedList::from_iter(0..3);
+        {
+            let mut c = list.cursor_mut();
+            assert_eq!(c.replace_current(9), None);
+            c.replace_current_with(|_| panic!("called at the empty element"));
+
+            c.move_next();
+            assert_eq!(c.replace_current(10), Some(0));
+            c.move_next();
+            assert_eq!(c.replace_current(11), Some(1));
+            assert_eq!(c.peek_before(), Some(&mut 10));
+            assert_eq!(c.peek(), Some(&mut 2));
+            c.move_next();
+            c.replace_current_with(|x| x + 10);
+            assert_eq!(c.current(), Some(&mut 12));
+        }
+        assert_eq!(list.len(), 3);
+        cmp_iterator(&list, 10..13);
+
+        let mut list = LinkedList::from_iter(vec![String::from("a")]);
+        list.cursor_front_mut().replace_current_with(|s| s + "b");
+        assert_eq!(list.front().map(|s| &s[..]), Some("ab"));
+    }
+    #[test]
+    fn seek_to() {
+        let mut list = LinkedList::from_iter(0..7);
+        let expected: Vec<_> = list.iter().cloned().collect();
+        let mut c = list.cursor_mut();
+        for &i in &[0, 6, 3, 4, 2, 0, 5] {
+            c.seek_to(i);
+            assert_eq!(c.current().cloned(), Some(expected[i]));
+            assert_eq!(c.current_len, i + 1);
+            let mut check = c.as_cursor();
+            check.move_prev();
+            assert_eq!(
+                check.current(),
+                if i == 0 { None } else { Some(&expected[i - 1]) }
+            );
+        }
+    }
+    #[test]
+    #[should_panic(expected = "index 3 out of bounds for list of length 3")]
+    fn seek_to_out_of_bounds() {
+        let mut list = LinkedList::from_iter(0..3);
+        list.cursor_mut().seek_to(3);
+    }
+    #[test]
+    fn move_if() {
+        let list = LinkedList::from_iter(vec![1, 2, 3, 0, 4]);
+        let mut c = list.cursor();
+        while c.move_next_if(|x| *x != 0) {}
+        assert_eq!(c.current(), Some(&3));
+        assert!(!c.move_next_if(|x| *x != 0));
+        assert!(c.move_prev_if(|x| *x == 2));
+        assert!(!c.move_prev_if(|x| *x == 2));
+        assert!(c.move_prev_if(|_| true));
+        // never onto the empty element
+        assert!(!c.move_prev_if(|_| true));
+        assert_eq!(c.current(), Some(&1));
+
+        let mut list = LinkedList::from_iter(vec![1, 2, 3, 0, 4]);
+        {
+            let mut c = list.cursor_mut();
+            while c.move_next_if(|x| *x != 0) {}
+            assert_eq!((c.current().cloned(), c.current_len), (Some(3), 3));
+            assert!(c.move_prev_if(|x| *x == 2));
+            assert_eq!(c.current_len, 2);
+            c.seek_to(4);
+            assert!(!c.move_next_if(|_| true));
+            assert_eq!(c.current(), Some(&mut 4));
+        }
+
+        let empty = LinkedList::<i32>::new();
+        let mut c = empty.cursor();
+        assert!(!c.move_next_if(|_| true));
+        assert!(!c.move_prev_if(|_| true));
+    }
+    #[test]
+    fn advance_retreat_by() {
+        let list = LinkedList::from_iter(0..4);
+        let mut c = list.cursor();
+        assert_eq!(c.advance_by(0), 0);
+        assert_eq!(c.current(), None);
+        assert_eq!(c.advance_by(4), 4);
+        assert_eq!(c.current(), Some(&3));
+        assert_eq!(c.advance_by(3), 1);
+        assert_eq!(c.current(), None);
+        assert_eq!(c.retreat_by(2), 2);
+        assert_eq!(c.current(), Some(&2));
+        assert_eq!(c.retreat_by(10), 3);
+        assert_eq!(c.current(), None);
+
+        let mut list = LinkedList::from_iter(0..4);
+        {
+            let mut c = list.cursor_mut();
+            assert_eq!(c.advance_by(2), 2);
+            assert_eq!((c.current().cloned(), c.current_len), (Some(1), 2));
+            assert_eq!(c.advance_by(10), 3);
+            assert_eq!((c.current().cloned(), c.current_len), (None, 0));
+            assert_eq!(c.retreat_by(1), 1);
+            assert_eq!((c.current().cloned(), c.current_len), (Some(3), 4));
+            assert_eq!(c.retreat_by(10), 4);
+            assert_eq!((c.current().cloned(), c.current_len), (None, 0));
+        }
+
+        let mut empty = LinkedList::<i32>::new();
+        assert_eq!(empty.cursor().advance_by(3), 0);
+        assert_eq!(empty.cursor_mut().retreat_by(3), 0);
+    }
+    #[test]
+    fn peek_nth() {
+        let list = LinkedList::from_iter(0..5);
+        let mut c = list.cursor();
+        assert_eq!(c.peek_next_nth(0), None);
+        assert_eq!(c.peek_next_nth(1), Some(&0));
+        assert_eq!(c.peek_next_nth(5), Some(&4));
+        assert_eq!(c.peek_next_nth(6), None);
+        assert_eq!(c.peek_prev_nth(1), Some(&4));
+        assert_eq!(c.peek_prev_nth(5), Some(&0));
+        assert_eq!(c.peek_prev_nth(6), None);
+
+        c.advance_by(2);
+        assert_eq!(c.peek_next_nth(0), c.current());
+        assert_eq!(c.peek_next_nth(1), c.peek());
+        assert_eq!(c.peek_next_nth(3), Some(&4));
+        assert_eq!(c.peek_next_nth(4), None);
+        assert_eq!(c.peek_prev_nth(0), Some(&1));
+        assert_eq!(c.peek_prev_nth(1), c.peek_before());
+        assert_eq!(c.peek_prev_nth(2), None);
+        assert_eq!(c.current(), Some(&1));
+    }
+    #[test]
+    fn insert_iter() {
+        let mut list = LinkedList::new();
+        list.cursor_mut().insert_iter(3..5);
+        cmp_iterator(&list, 3..5);
+        list.cursor_mut().insert_iter(0..2);
+        cmp_iterator(&list, vec![0, 1, 3, 4].into_iter());
+        {
+            let mut c = list.cursor_mut();
+            c.advance_by(2);
+            c.insert_iter(vec![2]);
+            assert_eq!(c.current(), Some(&mut 1));
+            c.retreat_by(2);
+            c.move_prev();
+            assert_eq!(c.current(), Some(&mut 4));
+            c.insert_iter(5..8);
+            c.insert_iter(None);
+        }
+        assert_eq!(list.len(), 8);
+        cmp_iterator(&list, 0..8);
+    }
+    #[test]
+    fn retain() {
+        let mut list = LinkedList::from_iter(0..6);
+        list.retain(|_| true);
+        cmp_iterator(&list, 0..6);
+        list.retain(|x| x % 3 != 1);
+        assert_eq!(list.len(), 4);
+        cmp_iterator(&list, vec![0, 2, 3, 5].into_iter());
+        list.retain(|_| false);
+        assert!(list.is_empty());
+        cmp_iterator(&list, 0..0);
+
+        let drops = Cell::new(0);
+        let mut list = LinkedList::from_iter((0..10).map(|i| (i, DropCounter(&drops))));
+        list.retain(|&(i, _)| i % 2 == 0);
+        assert_eq!(drops.get(), 5);
+        assert_eq!(list.len(), 5);
+        drop(list);
+        assert_eq!(drops.get(), 10);
+    }
+    #[test]
+    fn retain_mut() {
+        let mut list = LinkedList::from_iter(vec![4, -2, 8, 6, -10, 2]);
+        let mut calls = 0;
+        list.retain_mut(|x| {
+            calls += 1;
+            *x /= 2;
+            *x >= 0
+        });
+        assert_eq!(calls, 6);
+        cmp_iterator(&list, vec![2, 4, 3, 1].into_iter());
+
+        list.retain_mut(|x| *x % 2 == 0);
+        cmp_iterator(&list, vec![2, 4].into_iter());
+        list.retain_mut(|_| false);
+        cmp_iterator(&list, 0..0);
+    }
+    #[test]
+    fn dedup() {
+        fn test_dedup(input: Vec<i32>, expected: Vec<i32>) {
+            let mut list = LinkedList::from_iter(input);
+            list.dedup();
+            assert_eq!(list.len(), expected.len());
+            cmp_iterator(&list, expected.into_iter());
+        }
+
+        test_dedup(vec![1, 1, 1, 1], vec![1]);
+        test_dedup(vec![1, 2, 3], vec![1, 2, 3]);
+        test_dedup(vec![1, 1, 2, 1, 1, 2], vec![1, 2, 1, 2]);
+        test_dedup(vec![0, 0, 1, 2, 3, 3], vec![0, 1, 2, 3]);
+        test_dedup(vec![], vec![]);
+
+        let mut list = LinkedList::from_iter(vec![10, 11, 20, 21, 22, 10]);
+        list.dedup_by_key(|x| *x / 10);
+        cmp_iterator(&list, vec![10, 20, 10].into_iter());
+
+        // the kept element is passed second and can absorb the removed one
+        let mut list = LinkedList::from_iter(vec![1, 2, 4, 7, 8]);
+        list.dedup_by(|next, kept| {
+            if *next - *kept <= 2 {
+                *kept = *next;
+                true
+            } else {
+                false
+            }
+        });
+        cmp_iterator(&list, vec![4, 8].into_iter());
+    }
+    #[test]
+    fn reverse_in_place() {
+        for &n in &[0, 1, 2, 10] {
+            let mut list = LinkedList::from_iter(0..n);
+            list.reverse();
+            assert_eq!(list.len(), n);
+            cmp_iterator(&list, (0..n).rev());
+            mut_cmp_iterator(&mut list, (0..n).rev());
+            list.reverse();
+            cmp_iterator(&list, 0..n);
+        }
+    }
+    #[test]
+    fn sort() {
+        fn test_sort(input: Vec<i32>) {
+            let mut expected = input.clone();
+            expected.sort();
+            let mut list = LinkedList::from_iter(input);
+            list.sort();
+            assert_eq!(list.len(), expected.len());
+            cmp_iterator(&list, expected.into_iter());
+        }
+
+        let mut state = 7u32;
+        let random: Vec<i32> = (0..100)
+            .map(|_| {
+                state = state.wrapping_mul(1103515245).wrapping_add(12345);
+                (state >> 16) as i32 % 50
+            })
+            .collect();
+        test_sort(random);
+        test_sort((0..20).collect());
+        test_sort((0..20).rev().collect());
+        test_sort(vec![3; 10]);
+        test_sort(vec![1]);
+        test_sort(vec![]);
+
+        // elements with equal keys keep their original order
+        let mut list = LinkedList::from_iter(vec![(2, 0), (1, 1), (2, 2), (0, 3), (1, 4), (2, 5)]);
+        list.sort_by_key(|&(key, _)| key);
+        cmp_iterator(
+            &list,
+            vec![(0, 3), (1, 1), (1, 4), (2, 0), (2, 2), (2, 5)].into_iter(),
+        );
+        list.sort_by(|a, b| b.1.cmp(&a.1));
+        cmp_iterator(
+            &list,
+            vec![(2, 5), (1, 4), (0, 3), (2, 2), (1, 1), (2, 0)].into_iter(),
+        );
+    }
+    #[test]
+    fn merge() {
+        fn test_merge(a: Vec<i32>, b: Vec<i32>) {
+            let mut expected: Vec<_> = a.iter().chain(b.iter()).cloned().collect();
+            expected.sort();
+            let mut list = LinkedList::from_iter(a);
+            list.merge(LinkedList::from_iter(b));
+            assert_eq!(list.len(), expected.len());
+            cmp_iterator(&list, expected.into_iter());
+        }
+
+        test_merge(vec![0, 1, 2], vec![3, 4, 5]);
+        test_merge(vec![3, 4, 5], vec![0, 1, 2]);
+        test_merge(vec![0, 2, 4, 6], vec![1, 3, 5]);
+        test_merge(vec![0, 2], vec![]);
+        test_merge(vec![], vec![0, 2]);
+        test_merge(vec![], vec![]);
+
+        // on ties elements from `self` come first
+        let mut list = LinkedList::from_iter(vec![(0, 'a'), (1, 'a'), (1, 'b')]);
+        list.merge_by(LinkedList::from_iter(vec![(1, 'c'), (2, 'c')]), |a, b| {
+            a.0.cmp(&b.0)
+        });
+        cmp_iterator(
+            &list,
+            vec![(0, 'a'), (1, 'a'), (1, 'b'), (1, 'c'), (2, 'c')].into_iter(),
+        );
+    }
+    #[test]
+    #[should_panic(expected = "unsorted")]
+    #[cfg(debug_assertions)]
+    fn merge_unsorted() {
+        let mut list = LinkedList::from_iter(vec![1, 0]);
+        list.merge(LinkedList::new());
+    }
+    #[test]
+    fn split_at_index() {
+        for &i in &[0, 1, 3, 5, 6] {
+            let mut list = LinkedList::from_iter(0..6);
+            let tail = list.split_at_index(i);
+            assert_eq!((list.len(), tail.len()), (i, 6 - i));
+            cmp_iterator(&list, 0..i);
+            cmp_iterator(&tail, i..6);
+        }
+    }
+    #[test]
+    #[should_panic(expected = "index 4 out of bounds for list of length 3")]
+    fn split_at_index_out_of_bounds() {
+        LinkedList::from_iter(0..3).split_at_index(4);
+    }
+    #[test]
+    fn append() {
+        let mut list = LinkedList::from_iter(0..3);
+        let mut other = LinkedList::new();
+        list.append(&mut other);
+        cmp_iterator(&list, 0..3);
+
+        let mut empty = LinkedList::new();
+        empty.append(&mut list);
+        assert!(list.is_empty());
+        cmp_iterator(&empty, 0..3);
+
+        let mut other = LinkedList::from_iter(3..7);
+        empty.append(&mut other);
+        assert!(other.is_empty());
+        assert_eq!(empty.len(), 7);
+        cmp_iterator(&empty, 0..7);
+        cmp_iterator(&other, 0..0);
+    }
+    #[test]
+    fn splice_after() {
+        fn test_splice(at: Option<usize>, count: usize, taken: Vec<usize>, left: Vec<usize>) {
+            let mut list = LinkedList::from_iter(0..5);
+            let spliced = {
+                let mut c = list.cursor_mut();
+                if let Some(at) = at {
+                    c.seek_to(at);
+                }
+                c.splice_after(count)
+            };
+            assert_eq!(list.len() + spliced.len(), 5);
+            cmp_iterator(&spliced, taken.into_iter());
+            cmp_iterator(&list, left.into_iter());
+        }
+
+        test_splice(Some(1), 0, vec![], vec![0, 1, 2, 3, 4]);
+        test_splice(Some(1), 1, vec![2], vec![0, 1, 3, 4]);
+        test_splice(Some(1), 10, vec![2, 3, 4], vec![0, 1]);
+        test_splice(None, 2, vec![0, 1], vec![2, 3, 4]);
+        test_splice(None, 5, vec![0, 1, 2, 3, 4], vec![]);
+        test_splice(Some(4), 2, vec![], vec![0, 1, 2, 3, 4]);
+    }
+    #[test]
+    fn swap_with_neighbours() {
+        let mut list = LinkedList::from_iter(vec![5, 1, 4, 2, 3, 0]);
+        {
+            // bubble sort
+            let mut c = list.cursor_mut();
+            let mut swapped = true;
+            while swapped {
+                swapped = false;
+                c.seek_to(0);
+                while let (Some(a), Some(b)) = (c.as_cursor().current(), c.as_cursor().peek()) {
+                    if a > b {
+                        assert!(c.swap_with_next());
+                        swapped = true;
+                    }
+                    c.move_next();
+                }
+            }
+        }
+        cmp_iterator(&list, 0..6);
+
+        {
+            let mut c = list.cursor_mut();
+            assert!(!c.swap_with_next());
+            assert!(!c.swap_with_prev());
+            c.move_next();
+            assert!(!c.swap_with_prev());
+            assert!(c.swap_with_next());
+            assert_eq!(c.current(), Some(&mut 1));
+            c.seek_to(5);
+            assert!(!c.swap_with_next());
+            assert!(c.swap_with_prev());
+        }
+        cmp_iterator(&list, vec![1, 0, 2, 3, 5, 4].into_iter());
+    }
+    #[test]
+    fn partition() {
+        let (evens, odds) = LinkedList::from_iter(0..9).partition(|x| x % 2 == 0);
+        assert_eq!(evens.len() + odds.len(), 9);
+        cmp_iterator(&evens, (0..9).filter(|x| x % 2 == 0));
+        cmp_iterator(&odds, (0..9).filter(|x| x % 2 == 1));
+
+        let (all, none) = LinkedList::from_iter(0..3).partition(|_| true);
+        cmp_iterator(&all, 0..3);
+        cmp_iterator(&none, 0..0);
+        let (none, all) = LinkedList::from_iter(0..3).partition(|_| false);
+        cmp_iterator(&all, 0..3);
+        cmp_iterator(&none, 0..0);
+
+        let (one, none) = LinkedList::from_iter(0..1).partition(|_| true);
+        cmp_iterator(&one, 0..1);
+        cmp_iterator(&none, 0..0);
+        let (a, b) = LinkedList::<i32>::new().partition(|_| true);
+        assert!(a.is_empty() && b.is_empty());
+    }
+    #[test]
+    fn drain() {
+        let mut list = LinkedList::from_iter(0..5);
+        assert_eq!(list.drain().collect::<Vec<_>>(), vec![0, 1, 2, 3, 4]);
+        assert!(list.is_empty());
+        cmp_iterator(&list, 0..0);
+
+        let drops = Cell::new(0);
+        let mut list = LinkedList::from_iter((0..6).map(|i| (i, DropCounter(&drops))));
+        {
+            let mut drain = list.drain();
+            assert_eq!(drain.len(), 6);
+            assert_eq!(drain.next().map(|x| x.0), Some(0));
+            assert_eq!(drain.next_back().map(|x| x.0), Some(5));
+            assert_eq!(drain.next().map(|x| x.0), Some(1));
+            assert_eq!(drops.get(), 3);
+        }
+        assert_eq!(drops.get(), 6);
+        assert!(list.is_empty());
+
+        // the list is still usable afterwards
+        list.push_back((0, DropCounter(&drops)));
+        assert_eq!(list.len(), 1);
+    }
+    #[test]
+    fn extract_if() {
+        let mut list = LinkedList::from_iter(0..10);
+        let extracted: Vec<_> = list.extract_if(|x| *x % 2 == 1).collect();
+        assert_eq!(extracted, vec![1, 3, 5, 7, 9]);
+        assert_eq!(list.len(), 5);
+        cmp_iterator(&list, (0..10).filter(|x| x % 2 == 0));
+
+        assert_eq!(list.extract_if(|_| false).count(), 0);
+        cmp_iterator(&list, (0..10).filter(|x| x % 2 == 0));
+
+        // elements not yet reached are left alone
+        {
+            let mut it = list.extract_if(|x| {
+                *x += 1;
+                true
+            });
+            assert_eq!(it.next(), Some(1));
+            assert_eq!(it.next(), Some(3));
+        }
+        cmp_iterator(&list, vec![4, 6, 8].into_iter());
+
+        {
+            let mut it = list.extract_if(|_| true);
+            assert_eq!(it.by_ref().count(), 3);
+            assert_eq!(it.next(), None);
+        }
+        assert!(list.is_empty());
+        cmp_iterator(&list, 0..0);
+    }
+    #[test]
+    fn truncate() {
+        let drops = Cell::new(0);
+        let mut list = LinkedList::from_iter((0..6).map(|i| (i, DropCounter(&drops))));
+        list.truncate(6);
+        list.truncate(10);
+        assert_eq!((list.len(), drops.get()), (6, 0));
+        list.truncate(4);
+        assert_eq!((list.len(), drops.get()), (4, 2));
+        assert_eq!(list.back().map(|x| x.0), Some(3));
+        list.truncate(0);
+        assert_eq!((list.len(), drops.get()), (0, 6));
+        list.truncate(0);
+        assert!(list.is_empty());
+
+        let mut list = LinkedList::from_iter(0..5);
+        list.truncate(2);
+        cmp_iterator(&list, 0..2);
+    }
+    #[test]
+    fn clear() {
+        let mut empty = LinkedList::<i32>::new();
+        empty.clear();
+        assert!(empty.is_empty());
+
+        let drops = Cell::new(0);
+        let mut list = LinkedList::from_iter((0..1).map(|_| DropCounter(&drops)));
+        list.clear();
+        assert_eq!((list.len(), drops.get()), (0, 1));
+
+        let mut list = LinkedList::from_iter((0..5).map(|_| DropCounter(&drops)));
+        list.clear();
+        assert_eq!((list.len(), drops.get()), (0, 6));
+        assert!(list.is_empty());
+        assert!(list.front().is_none() && list.back().is_none());
+    }
+    #[test]
+    fn contains() {
+        let list = LinkedList::from_iter(vec![3, 1, 4]);
+        assert!(list.contains(&3));
+        assert!(list.contains(&1));
+        assert!(list.contains(&4));
+        assert!(!list.contains(&2));
+        assert!(list.contains_by(|x| x % 2 == 0));
+        assert!(!list.contains_by(|x| *x > 4));
+
+        assert!(!LinkedList::new().contains(&0));
+        let single = LinkedList::from_iter(0..1);
+        assert!(single.contains(&0));
+        assert!(!single.contains(&1));
+    }
+    #[test]
+    fn find_cursor() {
+        let mut list = LinkedList::from_iter(vec![1, 2, 3, 2]);
+        assert_eq!(list.find_cursor(|x| *x == 1).unwrap().current(), Some(&1));
+        let c = list.find_cursor(|x| *x == 2).unwrap();
+        assert_eq!(c.peek_before(), Some(&1));
+        let c = list.find_cursor(|x| *x > 2).unwrap();
+        assert_eq!(c.peek(), Some(&2));
+        assert!(list.find_cursor(|x| *x > 3).is_none());
+
+        {
+            let mut c = list.find_cursor_mut(|x| *x == 2).unwrap();
+            assert_eq!(c.current_len, 2);
+            c.remove_current();
+        }
+        cmp_iterator(&list, vec![1, 3, 2].into_iter());
+        {
+            let mut c = list.find_cursor_mut(|x| *x == 2).unwrap();
+            assert_eq!(c.current_len, 3);
+            assert_eq!(c.peek(), None);
+        }
+        assert!(list.find_cursor_mut(|_| false).is_none());
+
+        let mut single = LinkedList::from_iter(0..1);
+        assert!(single.find_cursor(|x| *x == 0).is_some());
+        assert!(single.find_cursor_mut(|x| *x == 1).is_none());
+    }
+    #[test]
+    fn position() {
+        let list = LinkedList::from_iter(vec![5, 1, 2, 1, 7]);
+        assert_eq!(list.position(|x| *x == 5), Some(0));
+        assert_eq!(list.rposition(|x| *x == 5), Some(0));
+        assert_eq!(list.position(|x| *x == 7), Some(4));
+        assert_eq!(list.rposition(|x| *x == 7), Some(4));
+        assert_eq!(list.position(|x| *x == 1), Some(1));
+        assert_eq!(list.rposition(|x| *x == 1), Some(3));
+        assert_eq!(list.position(|x| *x == 3), None);
+        assert_eq!(list.rposition(|x| *x == 3), None);
+
+        let empty = LinkedList::<i32>::new();
+        assert_eq!(empty.position(|_| true), None);
+        assert_eq!(empty.rposition(|_| true), None);
+    }
+    #[test]
+    fn get() {
+        let mut list = LinkedList::from_iter(0..5);
+        assert_eq!(list.get(0), Some(&0));
+        assert_eq!(list.get(4), Some(&4));
+        assert_eq!(list.get(5), None);
+        assert_eq!(list.get_mut(5), None);
+
+        *list.get_mut(2).unwrap() = 10;
+        assert_eq!(list.get(2), Some(&10));
+        cmp_iterator(&list, vec![0, 1, 10, 3, 4].into_iter());
+
+        let mut single = LinkedList::from_iter(vec![7]);
+        assert_eq!(single.get(0), Some(&7));
+        assert_eq!(single.get(1), None);
+        *single.get_mut(0).unwrap() += 1;
+        assert_eq!(single.get(0), Some(&8));
+
+        let mut empty = LinkedList::<i32>::new();
+        assert_eq!(empty.get(0), None);
+        assert_eq!(empty.get_mut(0), None);
+    }
+    #[test]
+    fn index() {
+        let mut list = LinkedList::from_iter(vec![String::from("a"), String::from("b")]);
+        assert_eq!(list[0], "a");
+        assert_eq!(list[1], "b");
+
+        list[1].push('c');
+        assert_eq!(list[1], "bc");
+        list[0] = list[1].clone();
+        assert_eq!(list[0], "bc");
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.get(0).map(String::as_str), Some("bc"));
+    }
+    #[test]
+    #[should_panic(expected = "index 3 out of bounds for list of length 3")]
+    fn index_out_of_bounds() {
+        let list = LinkedList::from_iter(0..3);
+        let _ = list[3];
+    }
+    #[test]
+    #[should_panic(expected = "index 0 out of bounds for list of length 0")]
+    fn index_mut_out_of_bounds() {
+        let mut list = LinkedList::<i32>::new();
+        list[0] = 1;
+    }
+    #[test]
+    fn remove() {
+        let mut list = LinkedList::from_iter(vec![1, 2, 3, 2, 4]);
+        assert!(!list.remove(&5));
+        cmp_iterator(&list, vec![1, 2, 3, 2, 4].into_iter());
+
+        assert!(list.remove(&1));
+        cmp_iterator(&list, vec![2, 3, 2, 4].into_iter());
+        assert!(list.remove(&4));
+        cmp_iterator(&list, vec![2, 3, 2].into_iter());
+        assert!(list.remove(&2));
+        cmp_iterator(&list, vec![3, 2].into_iter());
+        assert_eq!(list.len(), 2);
+
+        let mut single = LinkedList::from_iter(vec![0]);
+        assert!(single.remove(&0));
+        assert!(single.is_empty());
+        assert!(!single.remove(&0));
+    }
+    #[test]
+    fn remove_all() {
+        let mut list = LinkedList::from_iter(vec![1, 2, 1, 1, 3, 1]);
+        assert_eq!(list.remove_all(&4), 0);
+        assert_eq!(list.remove_all(&1), 4);
+        cmp_iterator(&list, vec![2, 3].into_iter());
+        assert_eq!(list.len(), 2);
+
+        let mut same = LinkedList::from_iter(vec![5; 3]);
+        assert_eq!(same.remove_all(&5), 3);
+        assert!(same.is_empty());
+        cmp_iterator(&same, Vec::new().into_iter());
+    }
+    #[test]
+    fn remove_at() {
+        for i in 0..5 {
+            let mut list = LinkedList::from_iter(0..5);
+            let expected = list.get(i).cloned();
+            assert_eq!(list.remove_at(i), expected);
+            assert_eq!(list.len(), 4);
+            cmp_iterator(&list, (0..5).filter(|&x| x != i).collect::<Vec<_>>().into_iter());
+        }
+
+        let mut list = LinkedList::from_iter(0..3);
+        assert_eq!(list.remove_at(3), None);
+        cmp_iterator(&list, 0..3);
+
+        let mut single = LinkedList::from_iter(vec![9]);
+        assert_eq!(single.remove_at(0), Some(9));
+        assert!(single.is_empty());
+        assert_eq!(single.remove_at(0), None);
+    }
+    #[test]
+    fn insert_at() {
+        let mut list = LinkedList::from_iter(1..3);
+        list.insert_at(0, 0);
+        cmp_iterator(&list, 0..3);
+        list.insert_at(3, 4);
+        cmp_iterator(&list, vec![0, 1, 2, 4].into_iter());
+        list.insert_at(3, 3);
+        cmp_iterator(&list, 0..5);
+        assert_eq!(list.len(), 5);
+
+        let mut empty = LinkedList::new();
+        empty.insert_at(0, 0);
+        cmp_iterator(&empty, 0..1);
+    }
+    #[test]
+    #[should_panic(expected = "index 4 out of bounds for list of length 3")]
+    fn insert_at_out_of_bounds() {
+        LinkedList::from_iter(0..3).insert_at(4, 0);
+    }
+    #[test]
+    fn cursor_iterator() {
+        let list = LinkedList::from_iter(0..5);
+        let mut c = list.cursor();
+        assert_eq!(c.by_ref().collect::<Vec<_>>(), vec![&0, &1, &2, &3, &4]);
+        assert_eq!(c.current(), None);
+
+        let mut c = list.cursor_front();
+        for (el, i) in c.by_ref().zip(1..) {
+            assert_eq!(*el, i);
+        }
+        assert_eq!(c.current(), None);
+
+        let mut c = list.cursor();
+        let small: Vec<_> = c.by_ref().take_while(|&&x| x < 2).collect();
+        assert_eq!(small, vec![&0, &1]);
+        // `take_while` stops on the first element that fails the predicate
+        assert_eq!(c.current(), Some(&2));
+        assert_eq!(c.by_ref().take(5).count(), 2);
+
+        assert_eq!(LinkedList::<i32>::new().cursor().next(), None);
+    }
+    #[test]
+    fn cursor_iter_mut() {
+        let mut list = LinkedList::from_iter(0..5);
+        {
+            let mut c = list.cursor_mut();
+            for el in c.iter_mut() {
+                *el *= 2;
+            }
+            assert_eq!(c.current(), None);
+            assert_eq!(c.iter_mut().count(), 5);
         }
+        cmp_iterator(&list, vec![0, 2, 4, 6, 8].into_iter());
         {
-            // test reverse iteration
-            let mut cursor = list.cursor();
-            let iter = iter.rev();
-
-            for i in iter {
-                cursor.move_prev();
-                //println!("{:?}", cursor.current());
-                assert_eq!(cursor.current(), Some(&i));
+            let mut c = list.cursor_mut();
+            c.seek_to(2);
+            for (el, i) in c.rev_iter_mut().zip(10..) {
+                *el = i;
             }
-            cursor.move_prev();
-            assert_eq!(cursor.current(), None);
+            assert_eq!(c.current(), None);
+        }
+        cmp_iterator(&list, vec![11, 10, 4, 6, 8].into_iter());
+        {
+            let mut c = list.cursor_back_mut();
+            let mut it = c.iter_mut();
+            assert_eq!(it.next(), None);
+            assert_eq!(it.next(), None);
         }
+
+        let mut empty = LinkedList::<i32>::new();
+        assert_eq!(empty.cursor_mut().iter_mut().next(), None);
+        assert_eq!(empty.cursor_mut().rev_iter_mut().next(), None);
     }
+    #[test]
+    fn collect_remaining() {
+        let list = LinkedList::from_iter(0..4);
+        let mut c = list.cursor();
+        assert_eq!(c.collect_remaining(), vec![&0, &1, &2, &3]);
+        assert_eq!(c.collect_remaining_rev(), vec![&3, &2, &1, &0]);
 
+        c.move_next();
+        c.move_next();
+        assert_eq!(c.collect_remaining(), vec![&1, &2, &3]);
+        assert_eq!(c.collect_remaining_rev(), vec![&1, &0]);
+        assert_eq!(c.current(), Some(&1));
+
+        let c = list.cursor_back();
+        assert_eq!(c.collect_remaining(), vec![&3]);
+        assert_eq!(c.collect_remaining_rev(), vec![&3, &2, &1, &0]);
+
+        let empty = LinkedList::<i32>::new();
+        assert!(empty.cursor().collect_remaining().is_empty());
+        assert!(empty.cursor().collect_remaining_rev().is_empty());
+    }
     #[test]
-    fn sanity_test() {
-        cmp_iterator(&LinkedList::from_iter(0..10), 0..10);
-        mut_cmp_iterator(&mut LinkedList::from_iter(0..10), 0..10);
+    fn windows() {
+        let list = LinkedList::from_iter(0..5);
+        let ones: Vec<[&i32; 1]> = list.windows().collect();
+        assert_eq!(ones, vec![[&0], [&1], [&2], [&3], [&4]]);
+        let pairs: Vec<_> = list.windows::<2>().map(|[a, b]| b - a).collect();
+        assert_eq!(pairs, vec![1; 4]);
+        let triples: Vec<[&i32; 3]> = list.windows().collect();
+        assert_eq!(triples, vec![[&0, &1, &2], [&1, &2, &3], [&2, &3, &4]]);
+        assert_eq!(list.windows::<5>().len(), 1);
+        assert_eq!(list.windows::<6>().next(), None);
+
+        // the references point into the list's own nodes
+        let [first, last] = list.windows::<2>().last().unwrap();
+        assert!(std::ptr::eq(first, list.get(3).unwrap()));
+        assert!(std::ptr::eq(last, list.back().unwrap()));
+
+        assert_eq!(LinkedList::<i32>::new().windows::<1>().next(), None);
     }
     #[test]
-    fn reverse() {
+    fn chunks() {
+        let list = LinkedList::from_iter(0..10);
+        let chunks: Vec<_> = list.chunks(3).collect();
+        assert_eq!(
+            chunks,
+            vec![
+                vec![&0, &1, &2],
+                vec![&3, &4, &5],
+                vec![&6, &7, &8],
+                vec![&9],
+            ]
+        );
+        assert_eq!(list.chunks(3).len(), 4);
+        assert_eq!(list.chunks(3).map(|c| c.len()).sum::<usize>(), list.len());
+        assert_eq!(list.chunks(1).len(), 10);
+        assert!(list.chunks(1).all(|c| c.len() == 1));
+
+        let short = LinkedList::from_iter(0..3);
+        let chunks: Vec<_> = short.chunks(10).collect();
+        assert_eq!(chunks, vec![vec![&0, &1, &2]]);
+
+        assert_eq!(LinkedList::<i32>::new().chunks(2).next(), None);
+    }
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn chunks_zero() {
+        LinkedList::from_iter(0..3).chunks(0);
+    }
+    #[test]
+    fn display() {
+        assert_eq!(LinkedList::<i32>::new().to_string(), "[]");
+        assert_eq!(LinkedList::from_iter(vec![1]).to_string(), "[1]");
+        let list = LinkedList::from_iter(vec![1, -2, 3]);
+        assert_eq!(list.to_string(), "[1, -2, 3]");
+        assert_eq!(format!("{:>2}", list), "[ 1, -2,  3]");
+
+        let parsed: LinkedList<i32> = list
+            .to_string()
+            .trim_matches(|c| c == '[' || c == ']')
+            .split(", ")
+            .map(|s| s.parse().unwrap())
+            .collect();
+        assert_eq!(parsed, list);
+    }
+    #[test]
+    fn vec_conversions() {
+        let list = LinkedList::from(vec![3, 1, 2]);
+        cmp_iterator(&list, vec![3, 1, 2].into_iter());
+        let vec: Vec<_> = list.into();
+        assert_eq!(vec, vec![3, 1, 2]);
+
+        let empty = LinkedList::from(Vec::<i32>::new());
+        assert!(empty.is_empty());
+        assert!(Vec::from(empty).is_empty());
+    }
+    #[test]
+    fn vec_deque_conversions() {
+        use std::collections::VecDeque;
+
+        let mut deque = VecDeque::new();
+        deque.push_back(2);
+        deque.push_front(1);
+        deque.push_back(3);
+        deque.push_front(0);
+        let list = LinkedList::from(deque.clone());
+        cmp_iterator(&list, 0..4);
+        assert_eq!(VecDeque::from(list), deque);
+
+        let single = LinkedList::from(VecDeque::from(vec![5]));
+        cmp_iterator(&single, 5..6);
+        assert_eq!(VecDeque::from(single), VecDeque::from(vec![5]));
+
+        let empty = LinkedList::from(VecDeque::<i32>::new());
+        assert!(empty.is_empty());
+        assert!(VecDeque::from(empty).is_empty());
+    }
+    #[test]
+    fn std_list_conversions() {
+        use std::collections::LinkedList as StdList;
+
+        let mut std_list = StdList::new();
+        std_list.push_back(1);
+        std_list.push_back(2);
+        std_list.push_front(0);
+        let list = LinkedList::from(std_list.clone());
+        cmp_iterator(&list, 0..3);
+        assert_eq!(StdList::from(list), std_list);
+
+        let single = LinkedList::from(StdList::from_iter(vec![7]));
+        cmp_iterator(&single, 7..8);
+        assert_eq!(StdList::from(single), StdList::from_iter(vec![7]));
+
+        let empty = LinkedList::from(StdList::<i32>::new());
+        assert!(empty.is_empty());
+        assert!(StdList::from(empty).is_empty());
+    }
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        extern crate serde_json;
+
+        let list = LinkedList::from_iter(0..3);
+        let json = serde_json::to_string(&list).unwrap();
+        assert_eq!(json, "[0,1,2]");
+        let back: LinkedList<i32> = serde_json::from_str(&json).unwrap();
+        cmp_iterator(&back, 0..3);
+
+        assert_eq!(serde_json::to_string(&LinkedList::<i32>::new()).unwrap(), "[]");
+        let empty: LinkedList<i32> = serde_json::from_str("[]").unwrap();
+        assert!(empty.is_empty());
+
+        let single = LinkedList::from_iter(vec![String::from("a")]);
+        let json = serde_json::to_string(&single).unwrap();
+        assert_eq!(json, "[\"a\"]");
+        assert_eq!(serde_json::from_str::<LinkedList<String>>(&json).unwrap(), single);
+
+        assert!(serde_json::from_str::<LinkedList<i32>>("{}").is_err());
+    }
+    #[test]
+    fn send_sync() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        use super::{CursorIterMut, Iter, IterMut, Windows};
+
+        assert_impl_all!(LinkedList<i32>: Send, Sync);
+        assert_impl_all!(Cursor<i32>: Send, Sync);
+        assert_impl_all!(CursorMut<i32>: Send, Sync);
+        assert_impl_all!(CursorIterMut<i32>: Send, Sync);
+        assert_impl_all!(Iter<i32>: Send, Sync);
+        assert_impl_all!(IterMut<i32>: Send, Sync);
+        assert_impl_all!(Windows<i32, 2>: Send, Sync);
+
+        assert_not_impl_any!(LinkedList<Rc<i32>>: Send, Sync);
+        assert_impl_all!(LinkedList<RefCell<i32>>: Send);
+        assert_not_impl_any!(LinkedList<RefCell<i32>>: Sync);
+        assert_not_impl_any!(Cursor<RefCell<i32>>: Send, Sync);
+        assert_impl_all!(CursorMut<RefCell<i32>>: Send);
+        assert_not_impl_any!(CursorMut<RefCell<i32>>: Sync);
+
         let list = LinkedList::from_iter(0..4);
-        let mut cursor = list.cursor();
-        for i in (0..4).rev() {
-            cursor.move_prev();
-            assert_eq!(cursor.current(), Some(&i));
+        let sum = std::thread::spawn(move || list.iter().sum::<i32>());
+        assert_eq!(sum.join().unwrap(), 6);
+    }
+    #[test]
+    fn variance() {
+        // these only compile if `Cursor` is covariant in `'list` and `T`, and
+        // `CursorMut` in `'list`
+        fn shorten_cursor<'a, 'b: 'a>(c: Cursor<'b, &'b str>) -> Cursor<'a, &'a str> {
+            c
+        }
+        fn shorten_cursor_mut<'a, 'b: 'a, T>(c: CursorMut<'b, T>) -> CursorMut<'a, T> {
+            c
         }
-        cursor.move_prev();
-        assert_eq!(cursor.current(), None);
     }
     #[test]
-    fn peek() {
-        let list = LinkedList::from_iter(3..5);
-        let cursor = list.cursor();
-        assert_eq!(cursor.peek(), Some(&3));
-        assert_eq!(cursor.peek_before(), Some(&4));
+    fn insert_list() {
+        let mut list = LinkedList::from_iter(vec![0, 3]);
+        {
+            let mut c = list.cursor_front_mut();
+            c.insert_list(LinkedList::from_iter(1..3));
+            c.insert_list(LinkedList::new());
+            assert_eq!(c.current(), Some(&mut 0));
+        }
+        cmp_iterator(&list, 0..4);
+        {
+            let mut c = list.cursor_back_mut();
+            c.insert_list_before(LinkedList::from_iter(vec![10, 11]));
+            c.insert_list_before(LinkedList::new());
+            assert_eq!(c.current(), Some(&mut 3));
+            c.move_next();
+            c.insert_list_before(LinkedList::from_iter(vec![12]));
+        }
+        cmp_iterator(&list, vec![0, 1, 2, 10, 11, 3, 12].into_iter());
+        assert_eq!(list.len(), 7);
+
+        let drops = Cell::new(0);
+        {
+            let mut list = LinkedList::from_iter(vec![DropCounter(&drops)]);
+            let mut c = list.cursor_mut();
+            c.insert_list(LinkedList::from_iter(vec![DropCounter(&drops)]));
+            c.insert_list_before(LinkedList::from_iter(vec![DropCounter(&drops)]));
+            assert_eq!(drops.get(), 0);
+        }
+        assert_eq!(drops.get(), 3);
     }
     #[test]
-    fn len() {
+    fn move_to_front_back() {
+        let mut list = LinkedList::from_iter(0..4);
+        {
+            let mut c = list.cursor_mut();
+            c.move_next();
+            c.move_next();
+            c.insert_before(10);
+            c.move_to_front();
+            assert_eq!(c.current(), Some(&mut 0));
+            assert_eq!(c.current_len, 1);
+            c.move_to_back();
+            assert_eq!(c.current(), Some(&mut 3));
+            assert_eq!(c.current_len, 5);
+
+            c.move_next();
+            assert_eq!(c.current(), None);
+            c.move_to_front();
+            assert_eq!(c.current(), Some(&mut 0));
+            c.move_prev();
+            c.move_to_back();
+            assert_eq!(c.current(), Some(&mut 3));
+        }
+        cmp_iterator(&list, vec![0, 10, 1, 2, 3].into_iter());
+
+        let mut empty = LinkedList::<i32>::new();
+        let mut c = empty.cursor_mut();
+        c.move_to_front();
+        assert_eq!(c.current(), None);
+        assert_eq!(c.current_len, 0);
+        c.move_to_back();
+        assert_eq!(c.current(), None);
+        assert_eq!(c.current_len, 0);
+    }
+    #[test]
+    fn rotate() {
         let mut list = LinkedList::from_iter(0..5);
-        assert_eq!(list.len, 5);
-        let list2 = {
-            let mut cursor = list.cursor_mut();
-            cursor.move_next();
-            cursor.move_next();
-            cursor.split()
-        };
-        assert_eq!(list.len, 2);
-        assert_eq!(list2.len, 3);
+        list.rotate_left(1);
+        cmp_iterator(&list, vec![1, 2, 3, 4, 0].into_iter());
+        list.rotate_right(1);
+        cmp_iterator(&list, 0..5);
+        list.rotate_left(4);
+        cmp_iterator(&list, vec![4, 0, 1, 2, 3].into_iter());
+        list.rotate_right(4);
+        cmp_iterator(&list, 0..5);
+        for &k in &[0, 5] {
+            list.rotate_left(k);
+            cmp_iterator(&list, 0..5);
+            list.rotate_right(k);
+            cmp_iterator(&list, 0..5);
+        }
+        assert_eq!(list.len(), 5);
+
+        let mut empty = LinkedList::<i32>::new();
+        empty.rotate_left(0);
+        empty.rotate_right(0);
+        assert!(empty.is_empty());
+    }
+    #[test]
+    #[should_panic(expected = "index 4 out of bounds for list of length 3")]
+    fn rotate_out_of_bounds() {
+        LinkedList::from_iter(0..3).rotate_right(4);
     }
+    #[test]
+    fn interleave() {
+        let evens = LinkedList::from_iter(vec![0, 2, 4]);
+        let odds = LinkedList::from_iter(vec![1, 3, 5]);
+        cmp_iterator(&evens.interleave(odds), 0..6);
 
-    /*
-        [Node:2] <- [List] ->   [Node:0]
-            None  <- [Node:0] -> [Node:1]
-        [Node:0] -> [Node:1] -> [Node:2]
-        [Node:1] -> [Node:2] ->  None
-    
-        [Node:0] <- [List] ->   [Node:0]
-            None  <- [Node:0] ->  None
-    
-        test cases:
-            [L] cursor "points" to list:
-                current = None (= List)
-            [H] cursor has no prev:
-                current = [Node:0]
-            [T] cursor has no next:
-                current = [Node:2]
-            [G] general case:
-                current = [Node:1]
-    
-            [S] single element, neither prev nor next
-    
-    */
+        let long = LinkedList::from_iter(vec![0, 2, 4, 5, 6]);
+        let short = LinkedList::from_iter(vec![1, 3]);
+        cmp_iterator(&long.clone().interleave(short.clone()), 0..7);
+        cmp_iterator(&short.interleave(long), vec![1, 0, 3, 2, 4, 5, 6].into_iter());
 
+        let list = LinkedList::from_iter(0..3);
+        cmp_iterator(&list.clone().interleave(LinkedList::new()), 0..3);
+        cmp_iterator(&LinkedList::new().interleave(list), 0..3);
+        assert!(LinkedList::<i32>::new().interleave(LinkedList::new()).is_empty());
+    }
     #[test]
-    fn split() {
-        fn test_split(n: usize, mut i: Option<usize>) {
-            let mut list = LinkedList::from_iter(0..n);
-            print!("split {:?} at {:?}", list, i);
-            let tail = {
-                let mut c = list.cursor_mut();
-                if let Some(i) = i {
-                    c.move_next();
-                    for _ in 0..i {
-                        c.move_next();
-                    }
-                }
-                println!(" = {:?}", c.current());
-                assert_eq!(i.as_mut(), c.current());
-                c.split()
-            };
-            println!("old: {:?}", list);
-            println!("new: {:?}", tail);
-            match i {
-                Some(i) => {
-                    cmp_iterator(&list, 0..=i);
-                    cmp_iterator(&tail, i + 1..n);
-                }
-                _ => {
-                    cmp_iterator(&list, 0..0);
-                    cmp_iterator(&tail, 0..n);
-                }
-            }
-        }
+    fn flatten() {
+        assert!(LinkedList::<LinkedList<i32>>::new().flatten().is_empty());
+        let empties = LinkedList::from_iter(vec![LinkedList::<i32>::new(), LinkedList::new()]);
+        assert!(empties.flatten().is_empty());
 
-        test_split(10, None); // case L
-        test_split(10, Some(0)); // case H
-        test_split(10, Some(9)); // case T
-        test_split(10, Some(3)); // case G
-        test_split(1, None); // case L
-        test_split(1, Some(0)); // case S
+        let uniform = LinkedList::from_iter(
+            (0..3).map(|i| LinkedList::from_iter(2 * i..2 * i + 2)),
+        );
+        let flat = uniform.flatten();
+        assert_eq!(flat.len(), 6);
+        cmp_iterator(&flat, 0..6);
+
+        let varying = LinkedList::from_iter(vec![
+            LinkedList::from_iter(0..1),
+            LinkedList::new(),
+            LinkedList::from_iter(1..4),
+            LinkedList::from_iter(4..6),
+        ]);
+        let flat = varying.flatten();
+        assert_eq!(flat.len(), 6);
+        cmp_iterator(&flat, 0..6);
     }
     #[test]
-    fn split_before() {
-        fn test_split(n: usize, i: Option<usize>) {
-            let mut list = LinkedList::from_iter(0..n);
-            print!("split {:?} before {:?}", list, i);
-            let tail = {
-                let mut c = list.cursor_mut();
-                if let Some(i) = i {
-                    c.move_next();
-                    for _ in 0..i {
-                        c.move_next();
-                    }
-                }
-                println!(" = {:?}", c.current());
-                c.split_before()
-            };
-            println!("old: {:?}", list);
-            println!("new: {:?}", tail);
-            match i {
-                Some(i) => {
-                    cmp_iterator(&list, 0..i);
-                    cmp_iterator(&tail, i..n);
-                }
-                _ => {
-                    cmp_iterator(&list, 0..0);
-                    cmp_iterator(&tail, 0..n);
-                }
-            }
+    fn zip() {
+        let zipped = LinkedList::from_iter(0..3).zip(LinkedList::from_iter(vec!['a', 'b', 'c']));
+        assert_eq!(zipped.len(), 3);
+        cmp_iterator(&zipped, vec![(0, 'a'), (1, 'b'), (2, 'c')].into_iter());
+
+        let zipped = LinkedList::from_iter(0..2).zip(LinkedList::from_iter(10..15));
+        assert_eq!(zipped.len(), 2);
+        cmp_iterator(&zipped, vec![(0, 10), (1, 11)].into_iter());
+        let zipped = LinkedList::from_iter(0..5).zip(LinkedList::from_iter(10..11));
+        assert_eq!(zipped.len(), 1);
+        cmp_iterator(&zipped, vec![(0, 10)].into_iter());
+
+        assert!(LinkedList::<i32>::new().zip(LinkedList::from_iter(0..3)).is_empty());
+        assert!(LinkedList::from_iter(0..3).zip(LinkedList::<i32>::new()).is_empty());
+        assert!(LinkedList::<i32>::new().zip(LinkedList::<i32>::new()).is_empty());
+
+        // elements left over in the longer list are dropped
+        let drops = Cell::new(0);
+        let long = LinkedList::from_iter((0..3).map(|_| DropCounter(&drops)));
+        let zipped = long.zip(LinkedList::from_iter(0..1));
+        assert_eq!(drops.get(), 2);
+        drop(zipped);
+        assert_eq!(drops.get(), 3);
+    }
+    #[test]
+    fn unzip() {
+        let zipped = LinkedList::from_iter(0..4).zip(LinkedList::from_iter(10..14));
+        let (a, b) = zipped.unzip();
+        assert_eq!(a.len(), 4);
+        assert_eq!(b.len(), 4);
+        cmp_iterator(&a, 0..4);
+        cmp_iterator(&b, 10..14);
+
+        let (a, b) = LinkedList::from_iter(vec![(1, 'x')]).unzip();
+        cmp_iterator(&a, 1..2);
+        cmp_iterator(&b, vec!['x'].into_iter());
+
+        let (a, b) = LinkedList::<(i32, char)>::new().unzip();
+        assert!(a.is_empty());
+        assert!(b.is_empty());
+    }
+    #[test]
+    fn map() {
+        let doubled = LinkedList::from_iter(0..4).map(|x| x * 2);
+        assert_eq!(doubled.len(), 4);
+        cmp_iterator(&doubled, vec![0, 2, 4, 6].into_iter());
+        let strings = LinkedList::from_iter(0..3).map(|x| x.to_string());
+        cmp_iterator(&strings, vec!["0", "1", "2"].into_iter().map(String::from));
+        assert!(LinkedList::<i32>::new().map(|x| x + 1).is_empty());
+    }
+    #[test]
+    fn scan() {
+        let sums = LinkedList::from_iter(1..5).scan(0, |sum, x| {
+            *sum += x;
+            *sum
+        });
+        assert_eq!(sums.len(), 4);
+        cmp_iterator(&sums, vec![1, 3, 6, 10].into_iter());
+        assert!(LinkedList::<i32>::new().scan(0, |_, x| x).is_empty());
+    }
+    #[test]
+    fn filter_map() {
+        let squares = LinkedList::from_iter(0..7)
+            .filter_map(|x| if x % 2 == 0 { Some(x * x) } else { None });
+        cmp_iterator(&squares, vec![0, 4, 16, 36].into_iter());
+        assert!(LinkedList::from_iter(0..3).filter_map(|_| None::<i32>).is_empty());
+        let all = LinkedList::from_iter(0..3).filter_map(Some);
+        assert_eq!(all.len(), 3);
+        cmp_iterator(&all, 0..3);
+        assert!(LinkedList::<i32>::new().filter_map(Some).is_empty());
+
+        let drops = Cell::new(0);
+        let kept = LinkedList::from_iter((0..4).map(|i| (i, DropCounter(&drops))))
+            .filter_map(|(i, d)| if i < 1 { Some(d) } else { None });
+        assert_eq!(kept.len(), 1);
+        assert_eq!(drops.get(), 3);
+    }
+    #[test]
+    fn flat_map() {
+        let divisors = LinkedList::from_iter(vec![4, 5, 6])
+            .flat_map(|n| LinkedList::from_iter((1..=n).filter(|d| n % d == 0)));
+        assert_eq!(divisors.len(), 9);
+        cmp_iterator(&divisors, vec![1, 2, 4, 1, 5, 1, 2, 3, 6].into_iter());
+
+        let empty = LinkedList::from_iter(0..3).flat_map(|_| LinkedList::<i32>::new());
+        assert!(empty.is_empty());
+        // only the odd numbers produce an element
+        let some_empty =
+            LinkedList::from_iter(0..4).flat_map(|x| LinkedList::from_iter(x..x % 2 + x));
+        cmp_iterator(&some_empty, vec![1, 3].into_iter());
+        let singletons =
+            LinkedList::from_iter(0..3).flat_map(|x| LinkedList::from_iter(vec![x * 2]));
+        assert_eq!(singletons, LinkedList::from_iter(0..3).map(|x| x * 2));
+        assert!(LinkedList::<i32>::new().flat_map(|x| LinkedList::from_iter(vec![x])).is_empty());
+    }
+    #[test]
+    fn count_if_any_all() {
+        let list = LinkedList::from_iter(vec![2, 4, 5, 6, 7]);
+        assert_eq!(list.count_if(|x| *x > 0), 5);
+        assert_eq!(list.count_if(|x| *x > 10), 0);
+        assert_eq!(list.count_if(|x| x % 2 == 0), 3);
+        assert_eq!(list.count_if(|x| *x == 7), 1);
+
+        assert!(list.any(|x| *x == 2));
+        assert!(list.any(|x| *x == 7));
+        assert!(!list.any(|x| *x > 10));
+        let mut checked = 0;
+        assert!(list.any(|x| {
+            checked += 1;
+            *x == 4
+        }));
+        assert_eq!(checked, 2);
+
+        assert!(list.all(|x| *x > 0));
+        assert!(!list.all(|x| *x != 2));
+        assert!(!list.all(|x| *x != 7));
+        let mut checked = 0;
+        assert!(!list.all(|x| {
+            checked += 1;
+            x % 2 == 0
+        }));
+        assert_eq!(checked, 3);
+
+        let empty = LinkedList::<i32>::new();
+        assert_eq!(empty.count_if(|_| true), 0);
+        assert!(!empty.any(|_| true));
+        assert!(empty.all(|_| false));
+    }
+    #[test]
+    fn fold_reduce() {
+        assert_eq!(LinkedList::from_iter(1..5).fold(0, |a, b| a + b), 10);
+        assert_eq!(LinkedList::from_iter(1..5).fold(1, |a, b| a * b), 24);
+        let digits = LinkedList::from_iter(1..4).fold(String::new(), |s, x| s + &x.to_string());
+        assert_eq!(digits, "123");
+        assert_eq!(LinkedList::<i32>::new().fold(7, |a, b| a + b), 7);
+
+        let list = LinkedList::from_iter(vec![3, 9, 1, 4]);
+        assert_eq!(list.clone().reduce(std::cmp::max), Some(9));
+        assert_eq!(list.clone().reduce(std::cmp::min), Some(1));
+        assert_eq!(list.reduce(|a, b| a - b), Some(-11));
+        assert_eq!(LinkedList::from_iter(vec![5]).reduce(|_, _| unreachable!()), Some(5));
+        assert_eq!(LinkedList::<i32>::new().reduce(|a, b| a + b), None);
+    }
+    #[test]
+    fn max_min() {
+        let list = LinkedList::from_iter(vec![3, 9, -1, 4]);
+        assert_eq!(list.clone().max(), Some(9));
+        assert_eq!(list.min(), Some(-1));
+        assert_eq!(LinkedList::from_iter(vec![5]).max(), Some(5));
+        assert_eq!(LinkedList::from_iter(vec![5]).min(), Some(5));
+        assert_eq!(LinkedList::<i32>::new().max(), None);
+        assert_eq!(LinkedList::<i32>::new().min(), None);
+
+        let pairs = LinkedList::from_iter(vec![(1, 'a'), (3, 'b'), (0, 'c'), (3, 'd'), (0, 'e')]);
+        assert_eq!(pairs.clone().max_by_key(|p| p.0), Some((3, 'd')));
+        assert_eq!(pairs.clone().min_by_key(|p| p.0), Some((0, 'c')));
+        // ties on the whole element follow the same rules
+        assert_eq!(pairs.clone().max_by_key(|_| 0), Some((0, 'e')));
+        assert_eq!(pairs.min_by_key(|_| 0), Some((1, 'a')));
+        assert_eq!(LinkedList::<i32>::new().max_by_key(|x| *x), None);
+        assert_eq!(LinkedList::<i32>::new().min_by_key(|x| *x), None);
+
+        // `Ord::max` is still available for comparing whole lists
+        let a = LinkedList::from_iter(0..2);
+        let b = LinkedList::from_iter(1..2);
+        assert_eq!(Ord::max(a, b.clone()), b);
+    }
+    #[test]
+    fn with_sentinel() {
+        let (mut list, sentinel) = LinkedList::with_sentinel(-1);
+        assert_eq!(list.len(), 1);
+        assert_eq!(list.front(), Some(&-1));
+        assert_eq!(unsafe { list.head.unwrap().as_ref().prev }, None);
+        list.push_back(1);
+        list.push_back(2);
+        assert_eq!(unsafe { list.head.unwrap().as_ref().prev }, None);
+        {
+            let mut c = list.cursor_back_mut();
+            assert!(c.seek_to_handle(sentinel));
+            assert_eq!(c.current(), Some(&mut -1));
+            assert_eq!(c.current_len, 1);
+            c.move_prev();
+            assert_eq!(c.current(), None);
+            c.move_next();
+            c.move_next();
+            assert_eq!(c.current(), Some(&mut 1));
         }
+        cmp_iterator(&list, vec![-1, 1, 2].into_iter());
 
-        test_split(10, None); // case L
-        test_split(10, Some(0)); // case L
-        test_split(10, Some(1)); // case H
-        test_split(10, Some(9)); // case T
-        test_split(10, Some(3)); // case G
-        test_split(1, None); // case L
-        test_split(1, Some(0)); // case S
+        // a handle stops matching once its node is removed
+        assert_eq!(list.pop_front(), Some(-1));
+        let mut c = list.cursor_front_mut();
+        assert!(!c.seek_to_handle(sentinel));
+        assert_eq!(c.current(), Some(&mut 1));
+        // and never matches a node of another list
+        let (_other, other_sentinel) = LinkedList::with_sentinel(0);
+        assert!(!c.seek_to_handle(other_sentinel));
+        assert_eq!(c.current(), Some(&mut 1));
     }
 }